@@ -0,0 +1,505 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::iter::Enumerate;
+use std::marker::PhantomData;
+use std::str::Lines;
+
+use harness::{generate::Rng, AocError};
+
+/// Parses a line of calories, ignoring whitespace around the number.
+pub fn parse_calories(index: usize, line: &str) -> Result<u64, AocError> {
+    let calories = line.trim();
+    calories.parse::<u64>().map_err(|_| AocError::at(index, line, calories, format!("Expected a number of calories, found `{}`", calories)))
+}
+
+/// An elf and every snack it carries.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    /// 0-based line of the elf's first snack, or of the blank line where an
+    /// empty group was found.
+    pub line: usize,
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    pub fn total(&self) -> u64 {
+        self.items.iter().sum()
+    }
+
+    pub fn largest(&self) -> Option<u64> {
+        self.items.iter().copied().max()
+    }
+}
+
+/// What `Elves` builds up for each elf as its snacks are read.
+pub trait Snacks {
+    /// An elf carrying nothing yet, found at the 0-based `line`.
+    fn start(index: usize, line: usize) -> Self;
+
+    fn add(&mut self, calories: u64);
+}
+
+impl Snacks for Elf {
+    fn start(index: usize, line: usize) -> Elf {
+        Elf { index, line, items: Vec::new() }
+    }
+
+    fn add(&mut self, calories: u64) {
+        self.items.push(calories);
+    }
+}
+
+/// Reads one elf per paragraph of calorie lines.
+///
+/// Lines holding only whitespace count as blank, and blank lines before the
+/// first elf or after the last are ignored, so the final elf is read whether
+/// or not the input ends with a blank line. Each extra blank line between two
+/// elves is an elf carrying nothing. Only the current elf is kept, so inputs
+/// of any length can be read, and reading `ElfTotal`s keeps just its sum.
+pub struct Elves<I: Iterator, E = Elf> {
+    lines: Enumerate<I>,
+    /// A line read while empty elves were still owed.
+    held: Option<(usize, I::Item)>,
+    /// Extra blank lines since the last elf, as the first and how many.
+    blanks: Option<(usize, usize)>,
+    count: usize,
+    elf: PhantomData<fn() -> E>,
+}
+
+/// Reads every elf with its snacks.
+pub fn elves<I: IntoIterator>(lines: I) -> Elves<I::IntoIter> {
+    Elves { lines: lines.into_iter().enumerate(), held: None, blanks: None, count: 0, elf: PhantomData }
+}
+
+/// Reads every elf, adding up its snacks as they are read.
+pub fn elf_totals<I: IntoIterator>(lines: I) -> Elves<I::IntoIter, ElfTotal> {
+    Elves { lines: lines.into_iter().enumerate(), held: None, blanks: None, count: 0, elf: PhantomData }
+}
+
+impl<I, E> Elves<I, E>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    fn finish(&mut self, elf: E) -> E {
+        self.count += 1;
+        elf
+    }
+}
+
+impl<I, E> Iterator for Elves<I, E>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+    E: Snacks,
+{
+    type Item = Result<E, AocError>;
+
+    fn next(&mut self) -> Option<Result<E, AocError>> {
+        let mut elf: Option<E> = None;
+        loop {
+            let Some((index, line)) = self.held.take().or_else(|| self.lines.next()) else {
+                return elf.map(|elf| Ok(self.finish(elf)));
+            };
+            if line.as_ref().trim().is_empty() {
+                if let Some(elf) = elf {
+                    return Some(Ok(self.finish(elf)));
+                }
+                if self.count > 0 {
+                    self.blanks = Some(self.blanks.map_or((index, 1), |(first, n)| (first, n + 1)));
+                }
+                continue;
+            }
+
+            if let Some((first, n)) = self.blanks.take() {
+                if n > 1 {
+                    self.blanks = Some((first + 1, n - 1));
+                }
+                self.held = Some((index, line));
+                let empty = E::start(self.count, first);
+                return Some(Ok(self.finish(empty)));
+            }
+            let count = self.count;
+            let elf = elf.get_or_insert_with(|| E::start(count, index));
+            match parse_calories(index, line.as_ref()) {
+                Ok(calories) => elf.add(calories),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// One elf's snacks, added up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ElfTotal {
+    /// Position of the elf in the input, from 0.
+    pub index: usize,
+    pub items: usize,
+    pub total: u64,
+}
+
+impl From<&Elf> for ElfTotal {
+    fn from(elf: &Elf) -> ElfTotal {
+        ElfTotal { index: elf.index, items: elf.items.len(), total: elf.total() }
+    }
+}
+
+impl Snacks for ElfTotal {
+    fn start(index: usize, _line: usize) -> ElfTotal {
+        ElfTotal { index, items: 0, total: 0 }
+    }
+
+    fn add(&mut self, calories: u64) {
+        self.items += 1;
+        self.total += calories;
+    }
+}
+
+/// Orders by total, breaking ties in favour of the elf listed first.
+impl Ord for ElfTotal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total.cmp(&other.total).then(other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for ElfTotal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The `k` elves carrying the most calories seen so far, kept in a min-heap
+/// of at most `k` entries so any number of elves fits in the same memory.
+#[derive(Clone, Debug)]
+pub struct TopElves {
+    k: usize,
+    heap: BinaryHeap<Reverse<ElfTotal>>,
+}
+
+impl TopElves {
+    pub fn new(k: usize) -> TopElves {
+        TopElves { k, heap: BinaryHeap::with_capacity(k + 1) }
+    }
+
+    pub fn offer(&mut self, elf: ElfTotal) {
+        self.heap.push(Reverse(elf));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// The elf carrying the most calories so far.
+    pub fn best(&self) -> Option<ElfTotal> {
+        self.heap.iter().map(|Reverse(elf)| *elf).max()
+    }
+
+    /// The kept elves, most calories first.
+    pub fn into_vec(self) -> Vec<ElfTotal> {
+        self.heap.into_sorted_vec().into_iter().map(|Reverse(elf)| elf).collect()
+    }
+}
+
+/// The `k` elves carrying the most calories, most first. There are fewer when
+/// there are fewer than `k` elves.
+pub fn top_elves<I>(lines: I, k: usize) -> Result<Vec<ElfTotal>, AocError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    top_elves_with(lines, k, |_, _| {})
+}
+
+/// Like `top_elves`, calling `progress` after each elf with it and the top
+/// elves so far. Only the current elf's total and the top `k` are kept, so
+/// `lines` and each elf's group can be as long as they like.
+pub fn top_elves_with<I>(lines: I, k: usize, mut progress: impl FnMut(&ElfTotal, &TopElves)) -> Result<Vec<ElfTotal>, AocError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut top = TopElves::new(k);
+    for elf in elf_totals(lines) {
+        let elf = elf?;
+        top.offer(elf);
+        progress(&elf, &top);
+    }
+    Ok(top.into_vec())
+}
+
+pub fn part1(lines: &mut Lines) -> Result<u64, AocError> {
+    Ok(top_elves(lines, 1)?.iter().map(|elf| elf.total).sum())
+}
+
+pub fn part2(lines: &mut Lines) -> Result<u64, AocError> {
+    Ok(top_elves(lines, 3)?.iter().map(|elf| elf.total).sum())
+}
+
+/// Every elf in an input, kept whole rather than summed on the fly, for
+/// checking that generated inputs look the way they should.
+#[derive(Clone, Debug, Default)]
+pub struct Inventory {
+    pub elves: Vec<Elf>,
+}
+
+impl Inventory {
+    pub fn parse(lines: &mut Lines) -> Result<Inventory, AocError> {
+        Ok(Inventory { elves: elves(lines).collect::<Result<_, _>>()? })
+    }
+
+    /// Totals of every elf, in input order.
+    pub fn totals(&self) -> Vec<u64> {
+        self.elves.iter().map(Elf::total).collect()
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        let totals = self.totals();
+        match totals.len() {
+            0 => None,
+            n => Some(totals.iter().map(|&t| t as f64).sum::<f64>() / n as f64),
+        }
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        let mut totals = self.totals();
+        totals.sort_unstable();
+        let n = totals.len();
+        match n {
+            0 => None,
+            _ if n % 2 == 1 => Some(totals[n / 2] as f64),
+            _ => Some((totals[n / 2 - 1] as f64 + totals[n / 2] as f64) / 2.0),
+        }
+    }
+
+    pub fn standard_deviation(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        let totals = self.totals();
+        let variance = totals.iter().map(|&t| (t as f64 - mean).powi(2)).sum::<f64>() / totals.len() as f64;
+        Some(variance.sqrt())
+    }
+
+    /// The elf carrying the largest single snack, with that snack.
+    pub fn largest_item(&self) -> Option<(&Elf, u64)> {
+        self.elves.iter().filter_map(|elf| Some((elf, elf.largest()?))).max_by_key(|&(elf, item)| (item, Reverse(elf.index)))
+    }
+
+    /// Elves worth a second look, with why: groups with no snacks, snacks of
+    /// no calories, and totals more than three standard deviations from the
+    /// mean.
+    pub fn suspicious(&self) -> Vec<(&Elf, String)> {
+        let (mean, deviation) = (self.mean().unwrap_or(0.0), self.standard_deviation().unwrap_or(0.0));
+        let mut found = Vec::new();
+        for elf in &self.elves {
+            if elf.items.is_empty() {
+                found.push((elf, String::from("carries nothing")));
+            } else if elf.items.contains(&0) {
+                found.push((elf, String::from("carries a snack of 0 calories")));
+            } else if deviation > 0.0 && (elf.total() as f64 - mean).abs() > 3.0 * deviation {
+                found.push((elf, format!("total {} is far from the mean", elf.total())));
+            }
+        }
+        found
+    }
+
+    /// Counts of totals in `bins` equal ranges from the smallest total to the
+    /// largest, as `(from, to, count)` with `to` inclusive.
+    pub fn histogram(&self, bins: usize) -> Vec<(u64, u64, usize)> {
+        let totals = self.totals();
+        let (Some(&min), Some(&max)) = (totals.iter().min(), totals.iter().max()) else {
+            return Vec::new();
+        };
+        let bins = bins.max(1);
+        let width = (max - min) / bins as u64 + 1;
+        let mut counts = vec![0; bins];
+        for total in totals {
+            counts[((total - min) / width) as usize] += 1;
+        }
+        counts.into_iter().enumerate().map(|(i, count)| (min + i as u64 * width, min + (i as u64 + 1) * width - 1, count)).collect()
+    }
+
+    /// Everything above, as printed by `aoc2022-day1 stats`.
+    pub fn report(&self) -> String {
+        let mut report = format!("Elves:\t\t{}\n", self.elves.len());
+        report.push_str(&format!("Snacks:\t\t{}\n", self.elves.iter().map(|elf| elf.items.len()).sum::<usize>()));
+        if let (Some(mean), Some(median), Some(deviation)) = (self.mean(), self.median(), self.standard_deviation()) {
+            report.push_str(&format!("Mean:\t\t{:.1}\nMedian:\t\t{:.1}\nStd dev:\t{:.1}\n", mean, median, deviation));
+        }
+        if let Some((elf, item)) = self.largest_item() {
+            report.push_str(&format!("Largest snack:\t{} (elf {}, line {})\n", item, elf.index + 1, elf.line + 1));
+        }
+
+        let suspicious = self.suspicious();
+        if !suspicious.is_empty() {
+            report.push_str("\nSuspicious\n");
+            for (elf, reason) in suspicious {
+                report.push_str(&format!("  elf {} (line {}): {}\n", elf.index + 1, elf.line + 1, reason));
+            }
+        }
+
+        let histogram = self.histogram(HISTOGRAM_BINS);
+        let most = histogram.iter().map(|&(_, _, count)| count).max().unwrap_or(0);
+        if most > 0 {
+            report.push_str("\nTotals\n");
+        }
+        for (from, to, count) in histogram {
+            let bar = "#".repeat((count * HISTOGRAM_WIDTH).div_ceil(most));
+            let line = format!("  {:>7}..={:<7} {:>6} {}", from, to, count, bar);
+            report.push_str(line.trim_end());
+            report.push('\n');
+        }
+        report
+    }
+}
+
+const HISTOGRAM_BINS: usize = 10;
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// `size` elves carrying 1 to 10 snacks each.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let elves: Vec<String> = (0..size)
+        .map(|_| {
+            let snacks: Vec<String> = (0..rng.range(1..=10)).map(|_| rng.range(1000..=60000).to_string()).collect();
+            snacks.join("\n")
+        })
+        .collect();
+    elves.join("\n\n") + "\n"
+}
+
+harness::solution! {
+    year: 2022,
+    day: 1,
+    title: "Calorie Counting",
+    tags: ["parsing"],
+    algorithms: ["summing groups", "top-k"],
+    difficulty: 1,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+" => { part1: 24000, part2: 45000 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indices(top: &[ElfTotal]) -> Vec<usize> {
+        top.iter().map(|elf| elf.index).collect()
+    }
+
+    fn read(input: &str) -> Vec<(usize, usize, Vec<u64>)> {
+        elves(input.lines()).map(|elf| elf.map(|elf| (elf.index, elf.line, elf.items)).unwrap()).collect()
+    }
+
+    #[test]
+    fn breaks_ties_in_favour_of_the_first_elf() {
+        let input = "5\n\n7\n\n3\n2\n\n7\n\n5";
+        assert_eq!(indices(&top_elves(input.lines(), 1).unwrap()), [1]);
+        assert_eq!(indices(&top_elves(input.lines(), 3).unwrap()), [1, 3, 0]);
+        assert_eq!(indices(&top_elves(input.lines(), 4).unwrap()), [1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn keeps_at_most_k_elves() {
+        let input = "1\n\n3\n\n2\n\n";
+        assert_eq!(top_elves(input.lines(), 0), Ok(vec![]));
+        let top = top_elves(input.lines(), 10).unwrap();
+        assert_eq!(top.iter().map(|elf| elf.total).collect::<Vec<_>>(), [3, 2, 1]);
+
+        let mut top = TopElves::new(2);
+        assert_eq!(top.best(), None);
+        for (index, total) in [(0, 4), (1, 9), (2, 6)] {
+            top.offer(ElfTotal { index, items: 1, total });
+        }
+        assert_eq!(top.best().map(|elf| elf.index), Some(1));
+        assert_eq!(indices(&top.into_vec()), [1, 2]);
+    }
+
+    #[test]
+    fn takes_the_median_of_odd_and_even_counts() {
+        let inventory = Inventory::parse(&mut "100\n\n300\n\n\n200\n0\n\n400".lines()).unwrap();
+        assert_eq!(inventory.totals(), [100, 300, 0, 200, 400]);
+        assert_eq!(inventory.median(), Some(200.0));
+        assert_eq!(inventory.mean(), Some(200.0));
+        let inventory = Inventory::parse(&mut "100\n\n400\n\n200\n\n300".lines()).unwrap();
+        assert_eq!(inventory.median(), Some(250.0));
+        assert_eq!(Inventory::default().median(), None);
+    }
+
+    #[test]
+    fn counts_totals_into_equal_bins() {
+        let inventory = Inventory::parse(&mut "100\n\n300\n\n\n200\n0\n\n400".lines()).unwrap();
+        assert_eq!(inventory.histogram(4), [(0, 100, 2), (101, 201, 1), (202, 302, 1), (303, 403, 1)]);
+        assert_eq!(inventory.histogram(0), [(0, 400, 5)]);
+        assert_eq!(Inventory::default().histogram(4), []);
+    }
+
+    #[test]
+    fn flags_suspicious_elves() {
+        let inventory = Inventory::parse(&mut "100\n\n300\n\n\n200\n0\n\n400".lines()).unwrap();
+        let found: Vec<_> = inventory.suspicious().into_iter().map(|(elf, reason)| (elf.index, reason)).collect();
+        assert_eq!(found, [(2, String::from("carries nothing")), (3, String::from("carries a snack of 0 calories"))]);
+
+        let input = "10\n\n".repeat(20) + "1000";
+        let inventory = Inventory::parse(&mut input.lines()).unwrap();
+        let found: Vec<_> = inventory.suspicious().into_iter().map(|(elf, reason)| (elf.index, reason)).collect();
+        assert_eq!(found, [(20, String::from("total 1000 is far from the mean"))]);
+    }
+
+    #[test]
+    fn treats_whitespace_only_lines_as_blank() {
+        assert_eq!(read(" \n1\n \t\n 2 \n3\n  "), [(0, 1, vec![1]), (1, 3, vec![2, 3])]);
+    }
+
+    #[test]
+    fn reads_extra_blank_lines_as_empty_elves() {
+        assert_eq!(read("\n1\n\n\n\n2\n\n"), [(0, 1, vec![1]), (1, 3, vec![]), (2, 4, vec![]), (3, 5, vec![2])]);
+    }
+
+    #[test]
+    fn reads_the_last_elf_with_or_without_a_blank_line() {
+        assert_eq!(read("1\n\n2"), read("1\n\n2\n\n"));
+        assert_eq!(read("1\n\n2").len(), 2);
+    }
+
+    #[test]
+    fn reports_a_malformed_line_where_it_is() {
+        let error = part1(&mut "1000\n\n 12a4 \n".lines()).unwrap_err();
+        assert_eq!(error.message, "Expected a number of calories, found `12a4`");
+        assert_eq!((error.line, error.column, error.len), (3, 2, 4));
+    }
+
+    #[test]
+    fn adds_totals_past_32_bits() {
+        let input = format!("{}\n1\n\n{}", i32::MAX, u32::MAX);
+        assert_eq!(part1(&mut input.lines()), Ok(u32::MAX as u64));
+        assert_eq!(part2(&mut input.lines()), Ok(i32::MAX as u64 + 1 + u32::MAX as u64));
+    }
+
+    #[test]
+    fn sums_elves_while_reading() {
+        let input = "\n1000\n2000\n\n\n\n4000\n0\n";
+        let totals: Vec<ElfTotal> = elf_totals(input.lines()).collect::<Result<_, _>>().unwrap();
+        let expected: Vec<ElfTotal> = elves(input.lines()).map(|elf| ElfTotal::from(&elf.unwrap())).collect();
+        assert_eq!(totals, expected);
+
+        // One elf with a million snacks, read without keeping them.
+        let group = std::iter::repeat_n("3", 1_000_000);
+        assert_eq!(top_elves(group, 1), Ok(vec![ElfTotal { index: 0, items: 1_000_000, total: 3_000_000 }]));
+    }
+}
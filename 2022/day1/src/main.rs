@@ -0,0 +1,61 @@
+use std::{
+    cell::Cell,
+    env,
+    io::{self, BufRead},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use aoc2022_day1::{ElfTotal, TopElves};
+
+/// How often `top` reports how far it has got.
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("stats") => stats(args.get(1).map(Path::new)),
+        Some("top") => top(args.get(1).map(String::as_str)),
+        _ => aoc2022_day1::SOLUTION.run(),
+    }
+}
+
+/// Prints the inventory report of the file at `path`, or of stdin.
+fn stats(path: Option<&Path>) {
+    let inventory = harness::parse_input(path, |input| aoc2022_day1::Inventory::parse(&mut input.lines()));
+    print!("{}", inventory.report());
+}
+
+/// Prints the `k` elves carrying the most calories, reading stdin a line at a
+/// time so inputs of any size fit in the same memory. Progress goes to stderr.
+fn top(k: Option<&str>) {
+    let k = match k.map(str::parse::<usize>) {
+        None => 3,
+        Some(Ok(k)) => k,
+        Some(Err(_)) => harness::fail("Usage: aoc2022-day1 top [<k>]"),
+    };
+    let lines_read = Cell::new(0);
+    let lines = io::stdin().lock().lines().map(|line| {
+        lines_read.set(lines_read.get() + 1);
+        line.unwrap_or_else(|e| harness::fail(format!("Error reading input: {}", e)))
+    });
+
+    let start = Instant::now();
+    let mut reported = start;
+    let mut elves = 0;
+    let progress = |elf: &ElfTotal, top: &TopElves| {
+        elves = elf.index + 1;
+        if reported.elapsed() >= PROGRESS_INTERVAL {
+            reported = Instant::now();
+            let best = top.best().map_or(0, |best| best.total);
+            eprintln!("{} elves, {} lines, most calories {} ({:?})", elves, lines_read.get(), best, start.elapsed());
+        }
+    };
+    let top = aoc2022_day1::top_elves_with(lines, k, progress).unwrap_or_else(|e| harness::fail(e));
+
+    for (rank, elf) in top.iter().enumerate() {
+        println!("{}.\telf {}\t{} calories in {} snacks", rank + 1, elf.index + 1, elf.total, elf.items);
+    }
+    println!("Total:\t{}", top.iter().map(|elf| elf.total).sum::<u64>());
+    eprintln!("Read {} elves in {:?}", elves, start.elapsed());
+}
@@ -0,0 +1,283 @@
+use std::str::Lines;
+use harness::{self, generate::Rng, AocError, FrameRecorder};
+
+/// `None` for `noop`, the value to add for `addx`.
+pub fn parse_instruction(index: usize, line: &str) -> Result<Option<i128>, AocError> {
+    match line.split_once(' ') {
+        None if line == "noop" => Ok(None),
+        Some(("addx", value)) => value
+            .parse::<i128>()
+            .map(Some)
+            .map_err(|_| AocError::at(index, line, value, format!("Expected a number to add, found `{}`", value))),
+        _ => Err(AocError::line(index, line, "Expected `noop` or `addx <value>`")),
+    }
+}
+
+pub fn part1(lines: &mut Lines) -> Result<i128, AocError> {
+    let mut cycle_count = 0;
+    let mut x_register: i128 = 1;
+    let mut signal_strengths: Vec<i128> = Vec::new();
+    let cycle_markers = [20, 60, 100, 140, 180, 220];
+    
+    for (index, line) in lines.enumerate() {
+        if let Some(to_add) = parse_instruction(index, line)? {
+            cycle_count += 1;
+            if cycle_markers.contains(&cycle_count) {
+                signal_strengths.push(cycle_count * x_register);
+            }
+            cycle_count += 1;
+            if cycle_markers.contains(&cycle_count) {
+                signal_strengths.push(cycle_count * x_register);
+            }
+            x_register += to_add;
+        } else {
+            cycle_count += 1;
+            if cycle_markers.contains(&cycle_count) {
+                signal_strengths.push(cycle_count * x_register);
+            }
+        }
+    }
+    
+    Ok(signal_strengths.iter().sum())
+}
+
+pub fn draw_pixel(screen: &mut String, cycle_count: i128, x_register: i128) {
+    if cycle_count % 40 == 0 {
+        screen.push('\n');
+    }
+
+    if cycle_count % 40 >= x_register - 1 && cycle_count % 40 <= x_register + 1 {
+        screen.push('#');
+    } else {
+        screen.push('.');
+    }
+}
+
+pub fn part2(lines: &mut Lines) -> Result<String, AocError> {
+    simulate_part2(lines, &mut FrameRecorder::new())
+}
+
+pub fn simulate_part2(lines: &mut Lines, recorder: &mut FrameRecorder) -> Result<String, AocError> {
+    let mut cycle_count = 0;
+    let mut x_register: i128 = 1;
+    let mut screen = String::new();
+    
+    for (index, line) in lines.enumerate() {
+        if let Some(to_add) = parse_instruction(index, line)? {
+            draw_pixel(&mut screen, cycle_count, x_register);
+            recorder.record(|| screen.clone());
+            cycle_count += 1;
+            draw_pixel(&mut screen, cycle_count, x_register);
+            recorder.record(|| screen.clone());
+            cycle_count += 1;
+            x_register += to_add;
+        } else {
+            draw_pixel(&mut screen, cycle_count, x_register);
+            recorder.record(|| screen.clone());
+            cycle_count += 1;
+        }
+    }
+    
+    Ok(screen)
+}
+
+/// A program of `size` instructions that keeps the sprite on the screen.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut x_register: i64 = 1;
+    let mut out = String::new();
+    for _ in 0..size {
+        let target = rng.range((x_register - 15).max(0)..=(x_register + 15).min(39));
+        if target == x_register || rng.chance(0.3) {
+            out.push_str("noop\n");
+        } else {
+            out.push_str(&format!("addx {}\n", target - x_register));
+            x_register = target;
+        }
+    }
+    out
+}
+
+harness::solution! {
+    year: 2022,
+    day: 10,
+    title: "Cathode-Ray Tube",
+    tags: ["simulation"],
+    algorithms: ["cycle counting", "crt rendering"],
+    difficulty: 2,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop" => {
+        part1: 13140,
+        part2: "
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....",
+    },
+}
+
+harness::snapshot_tests! {
+    example => [simulate_part2],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_a_value_that_is_not_a_number() {
+        let error = part1(&mut "noop\naddx five".lines()).unwrap_err();
+        assert_eq!(error.message, "Expected a number to add, found `five`");
+        assert_eq!((error.line, error.column, error.len), (2, 6, 4));
+    }
+}
@@ -0,0 +1,4 @@
+fn main() {
+    aoc2022_day10::SOLUTION.run();
+    harness::record_function("part2", "./data.txt", &aoc2022_day10::simulate_part2);
+}
@@ -0,0 +1,378 @@
+use std::{str::Lines, cell::RefCell, borrow::{BorrowMut}, fmt, io};
+
+use harness::{generate::Rng, repl::{self, Repl}, AocError};
+
+pub fn add(lhs: usize, rhs: usize) -> usize {
+    lhs + rhs
+}
+
+pub fn mul(lhs: usize, rhs: usize) -> usize {
+    match lhs.checked_mul(rhs) {
+        Some(m) => m,
+        None => {
+            (lhs % 10000) * (rhs % 10000)
+        }
+    }
+}
+
+pub struct Movement {
+    pub target_index: usize,
+    pub item: usize,
+}
+
+pub struct Monkey {
+    pub items: RefCell<Vec<usize>>,
+    pub operation: Box<dyn Fn(usize) -> usize>,
+    pub test: Box<dyn Fn(usize) -> bool>,
+    pub target_true_index: usize,
+    pub target_false_index: usize,
+    pub num_inspections: usize,
+}
+
+/// Strips the label off the `index`th line, erroring if it isn't there.
+fn field<'a>(index: usize, line: &'a str, label: &str) -> Result<&'a str, AocError> {
+    line.strip_prefix(label)
+        .ok_or_else(|| AocError::line(index, line, format!("Expected a line starting with `{}`", label.trim_start())))
+}
+
+fn number(index: usize, line: &str, s: &str) -> Result<usize, AocError> {
+    s.parse::<usize>().map_err(|_| AocError::at(index, line, s, format!("Expected a number, found `{}`", s)))
+}
+
+impl Monkey {
+    /// Parses the five lines after a `Monkey N:` header, the first being the
+    /// `index`th line of the input. Also returns the divisor of its test.
+    pub fn parse(index: usize, lines: &[&str]) -> Result<(Monkey, usize), AocError> {
+        let divisor = Monkey::parse_test_str(index + 2, lines[2])?;
+        let monkey = Monkey {
+            items: RefCell::new(Monkey::parse_items_str(index, lines[0])?),
+            operation: Box::new(Monkey::parse_operations_str(index + 1, lines[1])?),
+            test: Box::new(move |item| item % divisor == 0),
+            target_true_index: Monkey::parse_target_str(index + 3, lines[3], "    If true: throw to monkey ")?,
+            target_false_index: Monkey::parse_target_str(index + 4, lines[4], "    If false: throw to monkey ")?,
+            num_inspections: 0,
+        };
+        Ok((monkey, divisor))
+    }
+
+    pub fn parse_items_str(index: usize, input: &str) -> Result<Vec<usize>, AocError> {
+        field(index, input, "  Starting items: ")?
+            .split(", ")
+            .map(|s| number(index, input, s))
+            .collect()
+    }
+
+    pub fn parse_operations_str(index: usize, input: &str) -> Result<impl Fn(usize) -> usize, AocError> {
+        let eq = field(index, input, "  Operation: new = old ")?;
+        let Some((operator, operand)) = eq.split_once(' ') else {
+            return Err(AocError::at(index, input, eq, "Expected an operation like `old * 19`"));
+        };
+        let operation: fn(usize, usize) -> usize = match operator {
+            "*" => mul,
+            "+" => add,
+            _ => return Err(AocError::at(index, input, operator, format!("Expected `*` or `+`, found `{}`", operator))),
+        };
+        let rhs = match operand {
+            "old" => None,
+            _ => Some(number(index, input, operand)?),
+        };
+
+        Ok(move |old| operation(old, rhs.unwrap_or(old)))
+    }
+
+    pub fn parse_test_str(index: usize, input: &str) -> Result<usize, AocError> {
+        let divisor = field(index, input, "  Test: divisible by ")?;
+        match number(index, input, divisor)? {
+            0 => Err(AocError::at(index, input, divisor, "Cannot test divisibility by 0")),
+            divisor => Ok(divisor),
+        }
+    }
+
+    pub fn parse_target_str(index: usize, input: &str, label: &str) -> Result<usize, AocError> {
+        number(index, input, field(index, input, label)?)
+    }
+
+    pub fn take_turn(&mut self, reduce_worry: bool, max_required_worry: usize) -> Option<Movement> {
+        if self.items.borrow().is_empty() {
+            return None;
+        }
+        let current_item = self.items.borrow_mut().remove(0);
+        let mut new_item = (self.operation)(current_item);
+        if reduce_worry {
+            new_item /= 3;
+        }
+
+        new_item %= max_required_worry;
+
+        self.num_inspections += 1;
+        let target_monkey_index = if (self.test)(new_item) {
+            self.target_true_index
+        } else {
+            self.target_false_index
+        };
+        
+        Some(Movement { target_index: target_monkey_index, item: new_item })
+    }
+
+    pub fn take_turns(&mut self, reduce_worry: bool, max_required_worry: usize) -> Vec<Movement> {
+        let mut movements = Vec::new();
+        let mut cnt = true;
+        while cnt {
+            match self.take_turn(reduce_worry, max_required_worry) {
+                Some(m) => movements.push(m),
+                None => cnt = false,
+            };
+        }
+
+        movements
+    }
+}
+
+/// The monkeys in the middle of their game of keep away.
+pub struct Troop {
+    pub monkeys: Vec<Monkey>,
+    pub max_required_worry: usize,
+    pub reduce_worry: bool,
+    pub round: usize,
+}
+
+impl Troop {
+    pub fn parse(lines: &mut Lines, reduce_worry: bool) -> Result<Troop, AocError> {
+        let lines: Vec<&str> = lines.collect();
+        let mut monkeys: Vec<Monkey> = Vec::new();
+        // calculate LCM to limit my anxiety
+        let mut max_required_worry: usize = 1;
+        for (i, chunk) in lines.chunks(7).enumerate() {
+            let index = i * 7;
+            if chunk.len() < 6 {
+                return Err(AocError::end_of_input(index + chunk.len(), "Expected 6 lines describing a monkey"));
+            }
+            let expected = format!("Monkey {}:", monkeys.len());
+            if chunk[0] != expected {
+                return Err(AocError::line(index, chunk[0], format!("Expected `{}`", expected)));
+            }
+            let (m, divisor) = Monkey::parse(index + 1, &chunk[1..6])?;
+            max_required_worry = max_required_worry
+                .checked_mul(divisor)
+                .ok_or_else(|| AocError::line(index + 3, chunk[3], "Too many divisors to keep worry levels in range"))?;
+            monkeys.push(m);
+        }
+
+        for (i, m) in monkeys.iter().enumerate() {
+            for (offset, target) in [(4, m.target_true_index), (5, m.target_false_index)] {
+                if target >= monkeys.len() {
+                    let line = lines[i * 7 + offset];
+                    return Err(AocError::line(i * 7 + offset, line, format!("There is no monkey {}", target)));
+                }
+            }
+        }
+
+        Ok(Troop { monkeys, max_required_worry, reduce_worry, round: 0 })
+    }
+
+    pub fn play_round(&mut self) {
+        for i in 0..self.monkeys.len() {
+            let m = self.monkeys.get_mut(i).unwrap();
+            let movements = m.take_turns(self.reduce_worry, self.max_required_worry);
+            for movement in movements.iter() {
+                self.monkeys.get(movement.target_index).borrow_mut().unwrap().items.borrow_mut().push(movement.item);
+            }
+        }
+        self.round += 1;
+    }
+
+    pub fn monkey_business(&self) -> usize {
+        let mut inspections: Vec<usize> = self.monkeys.iter().map(|m| m.num_inspections).collect();
+        inspections.sort();
+        inspections.reverse();
+
+        inspections[0] * inspections[1]
+    }
+}
+
+impl fmt::Debug for Monkey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Monkey")
+            .field("items", &self.items.borrow())
+            .field("target_true_index", &self.target_true_index)
+            .field("target_false_index", &self.target_false_index)
+            .field("num_inspections", &self.num_inspections)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Debug for Troop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Troop")
+            .field("monkeys", &self.monkeys)
+            .field("max_required_worry", &self.max_required_worry)
+            .field("reduce_worry", &self.reduce_worry)
+            .field("round", &self.round)
+            .finish()
+    }
+}
+
+/// Lists the items each monkey holds, like the puzzle's round summaries.
+impl fmt::Display for Troop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "After round {}:", self.round)?;
+        for (i, m) in self.monkeys.iter().enumerate() {
+            let items: Vec<String> = m.items.borrow().iter().map(|item| item.to_string()).collect();
+            write!(f, "\nMonkey {}: {} (inspected {} items)", i, items.join(", "), m.num_inspections)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn part1(lines: &mut Lines) -> Result<usize, AocError> {
+    let mut troop = Troop::parse(lines, true)?;
+    for _ in 0..20 {
+        troop.play_round();
+    }
+    Ok(troop.monkey_business())
+}
+
+pub fn part2(lines: &mut Lines) -> Result<usize, AocError> {
+    let mut troop = Troop::parse(lines, false)?;
+    for _ in 0..10000 {
+        troop.play_round();
+    }
+    Ok(troop.monkey_business())
+}
+
+pub fn repl(lines: &mut Lines) -> io::Result<()> {
+    new_repl(lines)?.run()
+}
+
+/// The REPL `repl` runs, with the day's commands registered.
+pub fn new_repl(lines: &mut Lines) -> Result<Repl<Troop>, AocError> {
+    let repl = Repl::new("day11", Troop::parse(lines, true)?)
+        .command("step", "[n] play the next n rounds", |troop, args| {
+            for _ in 0..repl::count(args)? {
+                troop.play_round();
+            }
+            Ok(troop.to_string())
+        })
+        .command("render", "list the items each monkey holds", |troop, _| Ok(troop.to_string()))
+        .command("business", "show the current level of monkey business", |troop, _| {
+            if troop.monkeys.len() < 2 {
+                return Err(String::from("Need at least two monkeys"));
+            }
+            Ok(troop.monkey_business().to_string())
+        })
+        .command("relief", "toggle dividing worry levels by 3 after inspections", |troop, _| {
+            troop.reduce_worry = !troop.reduce_worry;
+            Ok(format!("Relief {}", if troop.reduce_worry { "on" } else { "off" }))
+        });
+    Ok(repl)
+}
+
+/// Four to eight monkeys sharing `size` starting items. Like real inputs,
+/// each monkey tests divisibility by a different prime.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut primes = vec![2, 3, 5, 7, 11, 13, 17, 19];
+    rng.shuffle(&mut primes);
+    let count = rng.range(4..=8) as usize;
+    // Every monkey starts with at least one item, the parser needs one.
+    let mut items: Vec<Vec<String>> = (0..count).map(|_| vec![rng.range(50..=99).to_string()]).collect();
+    for _ in count..size {
+        let monkey = rng.below(count);
+        items[monkey].push(rng.range(50..=99).to_string());
+    }
+
+    let mut monkeys = Vec::new();
+    for (i, monkey_items) in items.iter().enumerate() {
+        let operation = match rng.below(4) {
+            0 => String::from("old * old"),
+            1 => format!("old * {}", rng.range(2..=19)),
+            _ => format!("old + {}", rng.range(1..=8)),
+        };
+        let others: Vec<usize> = (0..count).filter(|&m| m != i).collect();
+        let target_true = *rng.pick(&others);
+        let target_false = loop {
+            let m = *rng.pick(&others);
+            if m != target_true {
+                break m;
+            }
+        };
+        monkeys.push(format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            i,
+            monkey_items.join(", "),
+            operation,
+            primes[i],
+            target_true,
+            target_false,
+        ));
+    }
+    monkeys.join("\n")
+}
+
+harness::solution! {
+    year: 2022,
+    day: 11,
+    title: "Monkey in the Middle",
+    tags: ["parsing", "simulation", "math"],
+    algorithms: ["modular arithmetic"],
+    difficulty: 3,
+    repl: repl,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1" => { part1: 10605, part2: 2713310158 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_a_test_for_divisibility_by_zero() {
+        let example = harness::testing::example_input(crate::example_tests::example::INPUT);
+        let input = example.replacen("divisible by 23", "divisible by 0", 1);
+        let error = part1(&mut input.lines()).unwrap_err();
+        assert_eq!(error.message, "Cannot test divisibility by 0");
+        assert_eq!((error.line, error.column, error.len), (4, 22, 1));
+    }
+
+    #[test]
+    fn plays_rounds_in_the_repl() {
+        let example = harness::testing::example_input(crate::example_tests::example::INPUT);
+        let mut repl = new_repl(&mut example.lines()).unwrap();
+        let mut execute = |line: &str| repl.execute(line).unwrap();
+        assert_eq!(execute("business"), Ok(String::from("0")));
+        assert!(execute("step 20").is_ok());
+        assert_eq!(execute("business"), Ok(String::from("10605")));
+        assert_eq!(execute("relief"), Ok(String::from("Relief off")));
+        assert_eq!(repl.state().round, 20);
+        assert!(!repl.state().reduce_worry);
+    }
+}
@@ -1,24 +1,25 @@
 use std::cell::RefCell;
 use std::str::Lines;
-use harness;
+
+use harness::{generate::Rng, AocError};
 
 #[derive(Debug, PartialEq, Eq)]
-enum NodeType {
+pub enum NodeType {
     Start,
     End,
     Generic,
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Node {
-    location: (usize, usize),
-    height: usize,
-    node_type: NodeType,
-    visited_at: Option<usize>
+pub struct Node {
+    pub location: (usize, usize),
+    pub height: usize,
+    pub node_type: NodeType,
+    pub visited_at: Option<usize>
 }
 
 impl Node {
-    fn new(row: usize, col: usize, height_char: char, node_type: NodeType) -> Node {
+    pub fn new(row: usize, col: usize, height_char: char, node_type: NodeType) -> Node {
         let height = match node_type {
             NodeType::Start => 1,
             NodeType::End => 26,
@@ -33,17 +34,51 @@ impl Node {
         }
     }
 
-    fn height_from_char(c: char) -> usize {
+    pub fn height_from_char(c: char) -> usize {
         let heightmap = "abcdefghijklmnopqrstuvwxyz";
         heightmap.find(c).unwrap() + 1
     }
 
-    fn can_traverse_to(&self, other: &Node) -> bool {
+    pub fn can_traverse_to(&self, other: &Node) -> bool {
         self.height + 1 >= other.height
     }
 }
 
-fn part1(lines: &mut Lines) -> usize {
+/// Checks the `index`th row has `cols` squares, each a height, `S` or `E`.
+pub fn check_row(index: usize, line: &str, cols: usize) -> Result<(), AocError> {
+    if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_lowercase() && *c != 'S' && *c != 'E') {
+        return Err(AocError::at(index, line, &line[i..i + c.len_utf8()], format!("Expected a height from a to z, S or E, found `{}`", c)));
+    }
+    if line.len() != cols {
+        return Err(AocError::line(index, line, format!("Expected {} squares like the first row, found {}", cols, line.len())));
+    }
+    Ok(())
+}
+
+/// Every heightmap needs exactly one `S` and one `E`.
+pub fn check_markers(lines: &Lines) -> Result<(), AocError> {
+    for marker in ['S', 'E'] {
+        let mut found = lines.clone().enumerate().filter_map(|(index, line)| line.find(marker).map(|col| (index, line, col)));
+        match (found.next(), found.next()) {
+            (None, _) => return Err(AocError::end_of_input(lines.clone().count(), format!("Expected an `{}` in the heightmap", marker))),
+            (Some(_), Some((index, line, col))) => return Err(AocError::at(index, line, &line[col..col + 1], format!("Found a second `{}`", marker))),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Error pointing at the `E` no path could reach.
+pub fn unreachable_end(lines: &Lines, message: &str) -> AocError {
+    let (index, line, col) = lines.clone().enumerate()
+        .find_map(|(index, line)| line.find('E').map(|col| (index, line, col)))
+        .expect("check_markers found an `E`");
+    AocError::at(index, line, &line[col..col + 1], message)
+}
+
+pub fn part1(lines: &mut Lines) -> Result<usize, AocError> {
+    check_markers(lines)?;
+    let input = lines.clone();
     let nodes: RefCell<Vec<Node>> = RefCell::new(Vec::new());
     let rows = lines.clone().count();
     let mut cols = 0;
@@ -54,6 +89,7 @@ fn part1(lines: &mut Lines) -> usize {
         if cols == 0 {
             cols = line.len();
         }
+        check_row(row, line, cols)?;
 
         for (col, height_char) in line.chars().enumerate() {
             let node_type = match height_char {
@@ -76,8 +112,8 @@ fn part1(lines: &mut Lines) -> usize {
 
     let mut answer = usize::MAX;
 
-    while !stack.is_empty() {
-        let (current_node_index, depth) = stack.pop().unwrap();
+    while let Some((current_node_index, depth)) = stack.pop() {
+        
         if depth > answer {
             continue;
         }
@@ -99,7 +135,7 @@ fn part1(lines: &mut Lines) -> usize {
 
             // get possible next steps
             // let index = current_row * cols + current_col;
-            let neighbor_locs = vec![
+            let neighbor_locs = [
                 (current_row.checked_sub(1), Some(current_col)), // top
                 (Some(current_row + 1), Some(current_col)), // bottom
                 (Some(current_row), current_col.checked_sub(1)), // left
@@ -126,10 +162,15 @@ fn part1(lines: &mut Lines) -> usize {
         }
     }
 
-    answer
+    if answer == usize::MAX {
+        return Err(unreachable_end(&input, "No path from S to E"));
+    }
+    Ok(answer)
 }
 
-fn part2(lines: &mut Lines) -> usize {
+pub fn part2(lines: &mut Lines) -> Result<usize, AocError> {
+    check_markers(lines)?;
+    let input = lines.clone();
     let nodes: RefCell<Vec<Node>> = RefCell::new(Vec::new());
     let rows = lines.clone().count();
     let mut cols = 0;
@@ -140,6 +181,7 @@ fn part2(lines: &mut Lines) -> usize {
         if cols == 0 {
             cols = line.len();
         }
+        check_row(row, line, cols)?;
 
         for (col, height_char) in line.chars().enumerate() {
             let node_type = match height_char {
@@ -165,8 +207,8 @@ fn part2(lines: &mut Lines) -> usize {
 
     let mut answer = usize::MAX;
 
-    while !stack.is_empty() {
-        let (current_node_index, depth) = stack.pop().unwrap();
+    while let Some((current_node_index, depth)) = stack.pop() {
+        
         if depth > answer {
             continue;
         }
@@ -188,7 +230,7 @@ fn part2(lines: &mut Lines) -> usize {
 
             // get possible next steps
             // let index = current_row * cols + current_col;
-            let neighbor_locs = vec![
+            let neighbor_locs = [
                 (current_row.checked_sub(1), Some(current_col)), // top
                 (Some(current_row + 1), Some(current_col)), // bottom
                 (Some(current_row), current_col.checked_sub(1)), // left
@@ -215,12 +257,77 @@ fn part2(lines: &mut Lines) -> usize {
         }
     }
 
-    answer
+    if answer == usize::MAX {
+        return Err(unreachable_end(&input, "No path from any `a` to E"));
+    }
+    Ok(answer)
+}
+
+/// A `size` by `size` heightmap, at least 14 wide. Heights rise evenly from
+/// `S` in the top left to `E` in the bottom right with random pits along the
+/// way. The top row and right column are never dug into, so `E` can always
+/// be reached.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(14);
+    let span = 2 * (size - 1);
+    let mut out = String::new();
+    for row in 0..size {
+        for col in 0..size {
+            let c = if (row, col) == (0, 0) {
+                'S'
+            } else if (row, col) == (size - 1, size - 1) {
+                'E'
+            } else {
+                let mut height = (row + col) * 25 / span;
+                if row != 0 && col != size - 1 {
+                    height = height.saturating_sub(rng.below(4));
+                }
+                (b'a' + height as u8) as char
+            };
+            out.push(c);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+harness::solution! {
+    year: 2022,
+    day: 12,
+    title: "Hill Climbing Algorithm",
+    tags: ["grid", "graph"],
+    algorithms: ["depth-first search"],
+    difficulty: 3,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi" => { part1: 31, part2: 29 },
 }
 
-fn main() {
-    harness::time_function("./example.txt", &part1);
-    harness::time_function("./data.txt", &part1);
-    harness::time_function("./example.txt", &part2);
-    harness::time_function("./data.txt", &part2);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_an_unreachable_end() {
+        let input = "Sbz\naaz\nzzE";
+        let error = part1(&mut input.lines()).unwrap_err();
+        assert_eq!(error.message, "No path from S to E");
+        assert_eq!((error.line, error.column), (3, 3));
+        let error = part2(&mut input.lines()).unwrap_err();
+        assert_eq!(error.message, "No path from any `a` to E");
+    }
+
+    #[test]
+    fn rejects_a_square_that_is_not_a_height() {
+        let error = part1(&mut "Sabc\nab!E".lines()).unwrap_err();
+        assert_eq!(error.message, "Expected a height from a to z, S or E, found `!`");
+        assert_eq!((error.line, error.column, error.len), (2, 3, 1));
+    }
 }
@@ -1,10 +1,11 @@
 use std::cmp::Ordering;
 use std::fmt::Debug;
 use std::str::Lines;
-use harness;
+
+use harness::{generate::Rng, AocError};
 
 #[derive(Clone, Debug, Eq)]
-enum Data {
+pub enum Data {
     Raw(usize),
     List(Vec<Data>),
 }
@@ -20,49 +21,49 @@ impl PartialEq for Data {
     }
 }
 
-impl PartialOrd for Data {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+impl Ord for Data {
+    fn cmp(&self, other: &Self) -> Ordering {
         match self {
             Data::List(list) => {
                 match other {
                     Data::List(other_list) => {
-                        if list.len() == 0 {
-                            if other_list.len() == 0 {
-                                Some(Ordering::Equal)
+                        if list.is_empty() {
+                            if other_list.is_empty() {
+                                Ordering::Equal
                             } else {
-                                Some(Ordering::Less)
+                                Ordering::Less
                             }
                         } else {
                             let mut li = list.iter();
                             let mut oli = other_list.iter();
                             
-                            let mut ret_val: Option<Ordering>;
+                            let mut ret_val: Ordering;
 
                             loop {
                                 let left_item = li.next();
                                 let right_item = oli.next();
 
                                 if left_item.is_none() && right_item.is_none() {
-                                    ret_val = Some(Ordering::Equal);
+                                    ret_val = Ordering::Equal;
                                     break;
                                 }
 
                                 if left_item.is_none() && right_item.is_some() {
-                                    ret_val = Some(Ordering::Less);
+                                    ret_val = Ordering::Less;
                                     break;
                                 }
 
                                 if left_item.is_some() && right_item.is_none() {
-                                    ret_val = Some(Ordering::Greater);
+                                    ret_val = Ordering::Greater;
                                     break;
                                 }
 
                                 let left_item = left_item.unwrap();
                                 let right_item = right_item.unwrap();
 
-                                ret_val = left_item.partial_cmp(right_item);
+                                ret_val = left_item.cmp(right_item);
 
-                                if ret_val != Some(Ordering::Equal) {
+                                if ret_val != Ordering::Equal {
                                     break;
                                 }
                             }
@@ -71,17 +72,17 @@ impl PartialOrd for Data {
                         }
                     },
                     Data::Raw(other_raw) => {
-                        self.partial_cmp(&Data::List(vec![Data::Raw(*other_raw)]))
+                        self.cmp(&Data::List(vec![Data::Raw(*other_raw)]))
                     }
                 }
             },
             Data::Raw(raw) => {
                 match other {
                     Data::List(_) => {
-                        Data::List(vec![Data::Raw(*raw)]).partial_cmp(other)
+                        Data::List(vec![Data::Raw(*raw)]).cmp(other)
                     },
                     Data::Raw(other_raw) => {
-                        raw.partial_cmp(other_raw)
+                        raw.cmp(other_raw)
                     },
                 }
             },
@@ -89,16 +90,49 @@ impl PartialOrd for Data {
     }
 }
 
-impl Ord for Data {
-    fn cmp(&self, other: &Self) -> Ordering {
-        match self.partial_cmp(other) {
-            Some(x) => x,
-            None => panic!("partial cmp returned none"),
+impl PartialOrd for Data {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Checks the `index`th line is a single well formed packet, so
+/// `parse_packet` can trust it.
+pub fn check_packet(index: usize, line: &str) -> Result<&str, AocError> {
+    if !line.starts_with('[') {
+        return Err(AocError::line(index, line, "Expected a packet starting with `[`"));
+    }
+    let mut depth = 0;
+    let mut number_start = None;
+    for (i, c) in line.char_indices() {
+        if c.is_ascii_digit() {
+            number_start.get_or_insert(i);
+            continue;
+        }
+        if let Some(start) = number_start.take() {
+            let number = &line[start..i];
+            if number.parse::<usize>().is_err() {
+                return Err(AocError::at(index, line, number, format!("Number `{}` is too large", number)));
+            }
+        }
+        match c {
+            '[' => depth += 1,
+            ']' if depth == 0 => return Err(AocError::at(index, line, &line[i..i + 1], "Unmatched `]`")),
+            ']' => depth -= 1,
+            ',' => {}
+            _ => return Err(AocError::at(index, line, &line[i..i + c.len_utf8()], format!("Expected a number, `[`, `]` or `,`, found `{}`", c))),
+        }
+        if depth == 0 && i + 1 < line.len() {
+            return Err(AocError::at(index, line, &line[i + 1..], "Expected the packet to end here"));
         }
     }
+    if depth > 0 {
+        return Err(AocError::line(index, line, format!("Expected {} more `]`", depth)));
+    }
+    Ok(line)
 }
 
-fn parse_packet(s: &str) -> Data {
+pub fn parse_packet(s: &str) -> Data {
     let s = &s[1..s.len()-1];
     let mut current_list: Vec<Data> = Vec::new();
     let mut working_packet: String = String::new();
@@ -129,7 +163,7 @@ fn parse_packet(s: &str) -> Data {
 
                 // finish number if is one
                 if !working_number.is_empty() {
-                    let num = usize::from_str_radix(working_number.as_str(), 10).unwrap();
+                    let num = working_number.as_str().parse::<usize>().unwrap();
                     current_list.push(Data::Raw(num));
                     working_number = String::new();
                 }
@@ -142,7 +176,7 @@ fn parse_packet(s: &str) -> Data {
 
                 // finish number if is one
                 if !working_number.is_empty() {
-                    let num = usize::from_str_radix(working_number.as_str(), 10).unwrap();
+                    let num = working_number.as_str().parse::<usize>().unwrap();
                     current_list.push(Data::Raw(num));
                     working_number = String::new();
                 }
@@ -160,24 +194,21 @@ fn parse_packet(s: &str) -> Data {
     }
 
     if !working_number.is_empty() {
-        let num = usize::from_str_radix(working_number.as_str(), 10).unwrap();
+        let num = working_number.as_str().parse::<usize>().unwrap();
         current_list.push(Data::Raw(num));
     }
     
-    let ret_val = Data::List(current_list);
-    ret_val
+    
+    Data::List(current_list)
 }
 
-fn compare_data(left: &Data, right: &Data) -> bool {
-    let in_right_order = match left.cmp(&right) {
-        Ordering::Greater => false,
-        _ => true,
-    };
+pub fn compare_data(left: &Data, right: &Data) -> bool {
+    
 
-    in_right_order
+    !matches!(left.cmp(right), Ordering::Greater)
 }
 
-fn part1(lines: &mut Lines) -> usize {
+pub fn part1(lines: &mut Lines) -> Result<usize, AocError> {
     let mut index = 1;
     let mut sum = 0;
     loop {
@@ -186,38 +217,36 @@ fn part1(lines: &mut Lines) -> usize {
         let right = it.next();
         it.next();
 
-        if left.is_none() || right.is_none() {
-            break;
-        }
-
-        let left = left.unwrap();
-        let right = right.unwrap();
+        let line_index = (index - 1) * 3;
+        let (left, right) = match (left, right) {
+            (Some(left), Some(right)) => (left, right),
+            (None, _) => break,
+            (Some(_), None) => return Err(AocError::end_of_input(line_index + 1, "Expected a second packet to pair with")),
+        };
 
-        let left_packet = parse_packet(left);
-        let right_packet = parse_packet(right);
+        let left_packet = parse_packet(check_packet(line_index, left)?);
+        let right_packet = parse_packet(check_packet(line_index + 1, right)?);
         
         if compare_data(&left_packet, &right_packet) {
             sum += index;
         }
         index += 1;
     }
-    sum
+    Ok(sum)
 }
 
-fn part2(lines: &mut Lines) -> usize {
+pub fn part2(lines: &mut Lines) -> Result<usize, AocError> {
     let mut packets: Vec<Data> = Vec::new();
-    let divider_packets = vec![
-        parse_packet("[[2]]"),
-        parse_packet("[[6]]"),
-    ];
+    let divider_packets = [parse_packet("[[2]]"),
+        parse_packet("[[6]]")];
     packets.push(divider_packets[0].clone());
     packets.push(divider_packets[1].clone());
 
-    for line in lines {
-        if line.len() == 0 {
+    for (index, line) in lines.enumerate() {
+        if line.is_empty() {
             continue;
         }
-        let packet = parse_packet(line);
+        let packet = parse_packet(check_packet(index, line)?);
         packets.push(packet);
     }
     packets.sort();
@@ -227,12 +256,69 @@ fn part2(lines: &mut Lines) -> usize {
             result *= index + 1;
         }
     }
-    result
+    Ok(result)
+}
+
+/// `size` pairs of packets nested up to four lists deep.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    fn packet(rng: &mut Rng, depth: usize) -> String {
+        let items: Vec<String> = (0..rng.range(0..=5))
+            .map(|_| match depth < 4 && rng.chance(0.3) {
+                true => packet(rng, depth + 1),
+                false => rng.range(0..=10).to_string(),
+            })
+            .collect();
+        format!("[{}]", items.join(","))
+    }
+    let pairs: Vec<String> = (0..size).map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0))).collect();
+    pairs.join("\n")
+}
+
+harness::solution! {
+    year: 2022,
+    day: 13,
+    title: "Distress Signal",
+    tags: ["parsing", "recursion"],
+    algorithms: ["recursive descent", "sorting"],
+    difficulty: 3,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]" => { part1: 13, part2: 140 },
 }
 
-fn main() {
-    harness::time_function("./example.txt", &part1);
-    harness::time_function("./data.txt", &part1);
-    harness::time_function("./example.txt", &part2);
-    harness::time_function("./data.txt", &part2);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_text_after_the_packet() {
+        let error = part1(&mut "[1,[2]]\n[1,2]]".lines()).unwrap_err();
+        assert_eq!(error.message, "Expected the packet to end here");
+        assert_eq!((error.line, error.column, error.len), (2, 6, 1));
+    }
 }
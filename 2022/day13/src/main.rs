@@ -0,0 +1,3 @@
+fn main() {
+    aoc2022_day13::SOLUTION.run();
+}
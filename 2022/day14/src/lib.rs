@@ -1,40 +1,49 @@
-use std::{str::Lines, collections::HashMap};
-// use std::thread::sleep;
-// use std::time;
-use harness;
+use std::{fmt, str::Lines, collections::HashMap};
+use harness::{self, generate::Rng, AocError, FrameRecorder};
 
 #[derive(Clone, Debug)]
-struct Sand {
-    location: (i32, i32),
+pub struct Sand {
+    pub location: (i32, i32),
 }
 
 #[derive(Clone, Debug)]
-struct RockStructure {
-    vertices: Vec<(i32, i32)>
+pub struct RockStructure {
+    pub vertices: Vec<(i32, i32)>
 }
 
 impl RockStructure {
-    fn new(s: &str) -> RockStructure {
-        let mut vertices = Vec::new();
+    /// Parses the `index`th line, a path of `x,y` vertices joined by ` -> `.
+    pub fn parse(index: usize, s: &str) -> Result<RockStructure, AocError> {
+        let mut vertices: Vec<(i32, i32)> = Vec::new();
         for v in s.split(" -> ") {
-            let nums = v.split(",")
-                .map(|x| i32::from_str_radix(x, 10).unwrap())
-                .collect::<Vec<i32>>();
-            vertices.push((nums[0], nums[1]));   
+            let Some((x, y)) = v.split_once(',') else {
+                return Err(AocError::at(index, s, v, format!("Expected a vertex like `498,4`, found `{}`", v)));
+            };
+            let number = |n: &str| n.parse::<i32>().map_err(|_| AocError::at(index, s, n, format!("Expected a number, found `{}`", n)));
+            let vert = (number(x)?, number(y)?);
+            if vert.1 < 0 {
+                return Err(AocError::at(index, s, y, format!("Expected a y of 0 or more, below the sand source, found `{}`", y)));
+            }
+            if let Some(prev) = vertices.last() {
+                if prev.0 != vert.0 && prev.1 != vert.1 {
+                    return Err(AocError::at(index, s, v, "Rock paths must be horizontal or vertical"));
+                }
+            }
+            vertices.push(vert);
         }
-        RockStructure { vertices }
+        Ok(RockStructure { vertices })
     }
 }
 
 
 #[derive(Debug)]
-enum SpaceMapItem {
+pub enum SpaceMapItem {
     Sand,
     Rock,
 }
 
 #[derive(PartialEq)]
-enum SandState {
+pub enum SandState {
     Resting,
     Falling,
     IntoTheVoid,
@@ -42,16 +51,16 @@ enum SandState {
 }
 
 #[derive(Debug)]
-struct Environment {
-    step_count: u128,
-    bounding_box: ((i32, i32), (i32, i32)),
-    space_map: HashMap<(i32, i32), SpaceMapItem>,
-    sand_spawn_loc: (i32, i32),
-    sand: Vec<Sand>,
+pub struct Environment {
+    pub step_count: u128,
+    pub bounding_box: ((i32, i32), (i32, i32)),
+    pub space_map: HashMap<(i32, i32), SpaceMapItem>,
+    pub sand_spawn_loc: (i32, i32),
+    pub sand: Vec<Sand>,
 }
 
 impl Environment {
-    fn new(rock_structures: Vec<RockStructure>, sand_spawn_loc: (i32, i32), with_floor: bool) -> Environment {
+    pub fn new(rock_structures: Vec<RockStructure>, sand_spawn_loc: (i32, i32), with_floor: bool) -> Environment {
         let mut space_map = HashMap::new();
         
         let mut min_x = sand_spawn_loc.0;
@@ -128,8 +137,10 @@ impl Environment {
         }
 
         if with_floor {
-            let height = 2*max_y-1;
-            for x in min_x-height..max_x+height {
+            // Sand piles at most `max_y + 1` either side of the source before
+            // reaching the floor, so it must be at least that wide.
+            let reach = max_y + 3;
+            for x in min_x.min(sand_spawn_loc.0 - reach)..=max_x.max(sand_spawn_loc.0 + reach) {
                 let loc = (x, max_y + 2);
                 space_map.insert(loc, SpaceMapItem::Rock);
             }
@@ -147,13 +158,13 @@ impl Environment {
         }
     }
 
-    fn add_sand(&mut self) {
+    pub fn add_sand(&mut self) {
         let new_sand =  Sand { location: self.sand_spawn_loc };
         self.sand.push(new_sand);
         self.space_map.insert(self.sand_spawn_loc, SpaceMapItem::Sand);
     }
 
-    fn update(&mut self) -> SandState {
+    pub fn update(&mut self) -> SandState {
         let mut moving_sand = self.sand.pop().unwrap();
         self.space_map.remove(&moving_sand.location);
 
@@ -191,8 +202,8 @@ impl Environment {
     }
 }
 
-impl ToString for Environment {
-    fn to_string(&self) -> String {
+impl fmt::Display for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 
         let extra_bounds = ((-5,-1),(5,5));
 
@@ -226,64 +237,127 @@ impl ToString for Environment {
             str.push('\n');
         }
         str.push('\n');
-        str
+        f.write_str(&str)
     }
 }
 
-fn part1(lines: &mut Lines) -> usize {
+pub fn part1(lines: &mut Lines) -> Result<usize, AocError> {
+    simulate_part1(lines, &mut FrameRecorder::new())
+}
+
+pub fn simulate_part1(lines: &mut Lines, recorder: &mut FrameRecorder) -> Result<usize, AocError> {
     let mut rock_structures = Vec::new();
-    for line in lines {
-        rock_structures.push(RockStructure::new(line));
+    for (index, line) in lines.enumerate() {
+        rock_structures.push(RockStructure::parse(index, line)?);
     }
     let sand_start_loc = (500, 0);
     let mut env = Environment::new(rock_structures, sand_start_loc, false);
     
     loop {
         env.add_sand();
-        // println!("{}", env.to_string());
+        recorder.record(|| env.to_string());
         
         loop {
             let is_resting = env.update();
-            // println!("{}", env.to_string());
-            // sleep(time::Duration::from_millis(1));
+            recorder.record(|| env.to_string());
             match is_resting {
                 SandState::Resting => break,
-                SandState::IntoTheVoid => return env.sand.len() - 1,
+                SandState::IntoTheVoid => return Ok(env.sand.len() - 1),
                 _ => continue,
             }
         }
     }
 }
 
-fn part2(lines: &mut Lines) -> usize {
+pub fn part2(lines: &mut Lines) -> Result<usize, AocError> {
+    simulate_part2(lines, &mut FrameRecorder::new())
+}
+
+pub fn simulate_part2(lines: &mut Lines, recorder: &mut FrameRecorder) -> Result<usize, AocError> {
     let mut rock_structures = Vec::new();
-    for line in lines {
-        rock_structures.push(RockStructure::new(line));
+    for (index, line) in lines.enumerate() {
+        rock_structures.push(RockStructure::parse(index, line)?);
     }
     let sand_start_loc = (500, 0);
+    let paths = rock_structures.len();
     let mut env = Environment::new(rock_structures, sand_start_loc, true);
     
     loop {
         env.add_sand();
-        // println!("{}", env.to_string());
+        recorder.record(|| env.to_string());
         
         loop {
             let is_resting = env.update();
-            // println!("{}", env.to_string());
-            // sleep(time::Duration::from_millis(10));
+            recorder.record(|| env.to_string());
             match is_resting {
                 SandState::Resting => break,
-                SandState::BlockingSpawn => return env.sand.len(),
-                SandState::IntoTheVoid => panic!("Shouldn't be a void!"),
+                SandState::BlockingSpawn => return Ok(env.sand.len()),
+                SandState::IntoTheVoid => {
+                    return Err(AocError::end_of_input(paths, "Sand fell past the floor"));
+                }
                 _ => continue,
             }
         }
     }
 }
 
-fn main() {
-    harness::time_function("./example.txt", &part1);
-    harness::time_function("./data.txt", &part1);
-    harness::time_function("./example.txt", &part2);
-    harness::time_function("./data.txt", &part2);
+/// `size` rock paths of horizontal and vertical lines below the sand source.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let (mut x, mut y) = (rng.range(450..=550), rng.range(5..=160));
+        let mut vertices = vec![format!("{},{}", x, y)];
+        let horizontal = rng.chance(0.5);
+        for i in 0..rng.range(1..=4) {
+            let length = rng.range(1..=8) * if rng.chance(0.5) { 1 } else { -1 };
+            if (i % 2 == 0) == horizontal {
+                x += length;
+            } else {
+                y = (y + length).max(1);
+            }
+            vertices.push(format!("{},{}", x, y));
+        }
+        out.push_str(&vertices.join(" -> "));
+        out.push('\n');
+    }
+    out
+}
+
+harness::solution! {
+    year: 2022,
+    day: 14,
+    title: "Regolith Reservoir",
+    tags: ["grid", "simulation"],
+    algorithms: ["falling sand"],
+    difficulty: 3,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9" => { part1: 24, part2: 93 },
+}
+
+harness::snapshot_tests! {
+    example => [simulate_part1, simulate_part2],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lays_a_floor_wide_enough_for_shallow_rocks() {
+        assert_eq!(part2(&mut "500,1 -> 500,1".lines()), Ok(8));
+        assert_eq!(part2(&mut "499,0 -> 501,0".lines()), Ok(4));
+        assert_eq!(part2(&mut "".lines()), Ok(4));
+    }
+
+    #[test]
+    fn rejects_rocks_above_the_sand_source() {
+        let error = part2(&mut "498,4 -> 498,6\n498,-5 -> 502,-5".lines()).unwrap_err();
+        assert_eq!(error.message, "Expected a y of 0 or more, below the sand source, found `-5`");
+        assert_eq!((error.line, error.column, error.len), (2, 5, 2));
+    }
 }
@@ -0,0 +1,5 @@
+fn main() {
+    aoc2022_day14::SOLUTION.run();
+    harness::record_function("part1", "./example.txt", &aoc2022_day14::simulate_part1);
+    harness::record_function("part2", "./example.txt", &aoc2022_day14::simulate_part2);
+}
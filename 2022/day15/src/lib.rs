@@ -0,0 +1,300 @@
+use std::collections::HashSet;
+use std::str::Lines;
+
+use harness::{generate::Rng, AocError};
+
+#[derive(Debug, Eq)]
+pub struct Location {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Location {
+    pub fn mag(&self) -> i32 {
+        self.x.abs() + self.y.abs()
+    }
+}
+
+impl PartialEq for Location {
+    fn eq(&self, other: &Self) -> bool {
+        self.mag()== other.mag()
+    }
+}
+
+impl PartialOrd for Location {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Location {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.mag().cmp(&other.mag())
+    }
+}
+
+#[derive(Debug)]
+pub struct Beacon {
+    pub location: Location,
+    pub distance: Location,
+}
+
+impl Beacon {
+    pub fn new(location: Location, closest_beacon: Location) -> Beacon {
+        let distance = Location { x: location.x - closest_beacon.x, y: location.y - closest_beacon.y };
+        Beacon {
+            location,
+            distance,
+        }
+    }
+
+    pub fn get_distance(&self, other: &Location) -> Location {
+        Location { x: self.location.x - other.x, y: self.location.y - other.y }
+    }
+}
+
+/// The example asks about row 10 and an area up to 20, real inputs about row
+/// 2000000 and an area up to 4000000. Real inputs have coordinates in the
+/// millions, so they are easy to tell apart.
+pub fn search_bounds(beacons: &[Beacon]) -> (i32, i32) {
+    if beacons.iter().all(|b| b.location.x.abs() <= 1000 && b.location.y.abs() <= 1000) {
+        (10, 20)
+    } else {
+        (2000000, 4000000)
+    }
+}
+
+pub type Position = (i32, i32);
+
+/// Parses the `index`th line into the sensor's position and its closest
+/// beacon's.
+pub fn parse_sensor(index: usize, line: &str) -> Result<(Position, Position), AocError> {
+    let format = || AocError::line(index, line, "Expected `Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>`");
+    let (sensor, beacon) = line
+        .strip_prefix("Sensor at ")
+        .and_then(|rest| rest.split_once(": closest beacon is at "))
+        .ok_or_else(format)?;
+    let location = |s: &str| {
+        let (x, y) = s.strip_prefix("x=").and_then(|s| s.split_once(", y=")).ok_or_else(format)?;
+        let number = |n: &str| n.parse::<i32>().map_err(|_| AocError::at(index, line, n, format!("Expected a number, found `{}`", n)));
+        Ok((number(x)?, number(y)?))
+    };
+    Ok((location(sensor)?, location(beacon)?))
+}
+
+pub fn part1(lines: &mut Lines) -> Result<usize, AocError> {
+    let mut min_dims: (i32, i32) = (i32::MAX, i32::MAX);
+    let mut max_dims: (i32, i32) = (0, 0);
+    let mut beacons: Vec<Beacon> = Vec::new();
+    let mut closest_beacons: HashSet<Position> = HashSet::new();
+    for (index, line) in lines.enumerate() {
+        let (location, closest_beacon) = parse_sensor(index, line)?;
+        let beacon = Beacon::new(
+            Location { x: location.0, y: location.1 },
+            Location { x: closest_beacon.0, y: closest_beacon.1 },
+        );
+        closest_beacons.insert(closest_beacon);
+
+        if beacon.location.x + beacon.distance.mag() > max_dims.0 {
+            max_dims.0 = beacon.location.x + beacon.distance.mag();
+        }
+
+        if beacon.location.y + beacon.distance.mag() > max_dims.1 {
+            max_dims.1 = beacon.location.y + beacon.distance.mag();
+        }
+
+        if beacon.location.x - beacon.distance.mag() < min_dims.0 {
+            min_dims.0 = beacon.location.x - beacon.distance.mag();
+        }
+
+        if beacon.location.y - beacon.distance.mag() < min_dims.1 {
+            min_dims.1 = beacon.location.y - beacon.distance.mag();
+        }
+
+        beacons.push(beacon);
+    }
+    
+    let (chosen_row, _) = search_bounds(&beacons);
+
+    // get all beacons that do not have a "service zone" which overlaps the chosen row
+    let mut relevant_beacons = Vec::new();
+    for beacon in beacons.iter() {
+        let other_loc = Location { x: beacon.location.x, y: chosen_row };
+        if beacon.get_distance(&other_loc) <= beacon.distance {
+            // should be included
+            relevant_beacons.push(beacon);
+        }
+    }
+
+
+    let mut result = 0;
+    for x in min_dims.0..max_dims.0+1 {
+        let current_location = Location{ x, y: chosen_row };
+        if closest_beacons.contains(&(x, chosen_row)) {
+            continue;
+        }
+        for beacon in relevant_beacons.iter() {
+            if beacon.get_distance(&current_location) <= beacon.distance { 
+                result += 1;
+                break;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn part2(lines: &mut Lines) -> Result<i64, AocError> {
+    let mut min_dims: (i32, i32) = (i32::MAX, i32::MAX);
+    let mut max_dims: (i32, i32) = (0, 0);
+    let mut beacons: Vec<Beacon> = Vec::new();
+    let mut closest_beacons: HashSet<Position> = HashSet::new();
+    for (index, line) in lines.enumerate() {
+        let (location, closest_beacon) = parse_sensor(index, line)?;
+        let beacon = Beacon::new(
+            Location { x: location.0, y: location.1 },
+            Location { x: closest_beacon.0, y: closest_beacon.1 },
+        );
+        closest_beacons.insert(closest_beacon);
+
+        if beacon.location.x + beacon.distance.mag() > max_dims.0 {
+            max_dims.0 = beacon.location.x + beacon.distance.mag();
+        }
+
+        if beacon.location.y + beacon.distance.mag() > max_dims.1 {
+            max_dims.1 = beacon.location.y + beacon.distance.mag();
+        }
+
+        if beacon.location.x - beacon.distance.mag() < min_dims.0 {
+            min_dims.0 = beacon.location.x - beacon.distance.mag();
+        }
+
+        if beacon.location.y - beacon.distance.mag() < min_dims.1 {
+            min_dims.1 = beacon.location.y - beacon.distance.mag();
+        }
+
+        beacons.push(beacon);
+    }
+    
+    let (_, max_dim) = search_bounds(&beacons);
+
+
+    let result: i64;
+    let mut x = 0;
+    let mut y = 0;
+    loop {
+        let current_location = Location{ x, y };
+        // Beacons are kept by position, as `Location` compares magnitudes.
+        if !closest_beacons.contains(&(x, y)) {
+            let mut overlapped = false;
+            for beacon in beacons.iter() {
+                let dist = beacon.get_distance(&current_location);
+                if dist <= beacon.distance {
+                    let distance_to_move_by = beacon.distance.mag() - dist.mag();
+                    if x + distance_to_move_by > max_dim {
+                        // determine whether we can skip more rows or not by checking the maximum x-value covered by this beacon is at this y-value
+                        // if that value is less than 0 and the max-x-value is greater than MAX_DIM, we can skip a number of rows equal to that minimum
+                        let x_dist_remaining = (beacon.distance.mag() - (beacon.location.y - dist.y).abs()).abs();
+                        let min_x = current_location.x - x_dist_remaining;
+                        let max_x = current_location.x + x_dist_remaining;
+                        if min_x <= 0 && max_x >= 0 {
+                            x = 0;
+                            y += min_x.abs().min(max_x);
+                        } else {
+                            x = 0;
+                            y += 1;
+                        }
+                    } else {
+                        x += distance_to_move_by + 1;
+                    }
+                    overlapped = true;
+                    break;
+                }
+            }
+
+            if !overlapped {
+                result = (current_location.x as i64) * 4000000 + (current_location.y as i64);
+                break;
+            }
+        } else {
+            x += 1;
+        }
+
+        if x > max_dim {
+            x = 0;
+            y += 1;
+        }
+    }
+
+    Ok(result)
+}
+
+/// `size` sensors spread over the 4000000 square real inputs search, each
+/// with a beacon up to 1000000 away on either axis.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let (x, y) = (rng.range(0..=4000000), rng.range(0..=4000000));
+            let beacon_x = x + rng.range(-1000000..=1000000);
+            let beacon_y = y + rng.range(-1000000..=1000000);
+            format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", x, y, beacon_x, beacon_y)
+        })
+        .collect()
+}
+
+harness::solution! {
+    year: 2022,
+    day: 15,
+    title: "Beacon Exclusion Zone",
+    tags: ["geometry"],
+    algorithms: ["manhattan distance", "range skipping"],
+    difficulty: 4,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3" => { part1: 26, part2: 56000011 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_cells_with_the_magnitude_of_a_beacon_on_the_row() {
+        // Covers x = -2..=2 on row 10 with its beacon at (2, 10), the same
+        // magnitude as (-2, 10).
+        let input = "Sensor at x=0, y=10: closest beacon is at x=2, y=10";
+        assert_eq!(part1(&mut input.lines()), Ok(4));
+    }
+
+    #[test]
+    fn finds_the_gap_with_the_magnitude_of_a_beacon() {
+        // The example's gap at (14, 11) has the magnitude of the added
+        // beacon at (-25, 0), whose sensor is outside the searched area.
+        let example = harness::testing::example_input(crate::example_tests::example::INPUT);
+        let input = format!("{}\nSensor at x=-26, y=0: closest beacon is at x=-25, y=0", example.trim_end());
+        assert_eq!(part2(&mut input.lines()), Ok(56000011));
+    }
+
+    #[test]
+    fn rejects_a_coordinate_that_is_not_a_number() {
+        let error = part1(&mut "Sensor at x=2, y=18: closest beacon is at x=-2, y=1o".lines()).unwrap_err();
+        assert_eq!(error.message, "Expected a number, found `1o`");
+        assert_eq!((error.line, error.column, error.len), (1, 51, 2));
+    }
+}
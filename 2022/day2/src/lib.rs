@@ -0,0 +1,833 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    fmt,
+    str::{FromStr, Lines},
+    sync::LazyLock,
+};
+
+use harness::{generate::Rng, AocError};
+
+/// A move of a cyclic game, with its shape score and the codes a strategy
+/// guide uses for it in each column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shape {
+    pub name: String,
+    pub score: i32,
+    pub enemy_code: String,
+    pub my_code: String,
+}
+
+/// What a game's moves are, what beats what, and how rounds are scored.
+/// Moves are referred to by their index in `shapes`.
+#[derive(Clone, Debug)]
+pub struct Rules {
+    pub shapes: Vec<Shape>,
+    /// The moves each move beats.
+    pub beats: Vec<Vec<usize>>,
+    /// Scores for a loss, a draw and a win.
+    pub outcome_scores: [i32; 3],
+    /// Codes for a loss, a draw and a win in the guide's second column.
+    pub result_codes: [String; 3],
+}
+
+static CLASSIC: LazyLock<Rules> =
+    LazyLock::new(|| Rules::cyclic(&["Rock", "Paper", "Scissors"]).expect("Rock Paper Scissors is a valid game"));
+
+impl Rules {
+    /// Checks that every two different moves have exactly one winner, and
+    /// that every move beats something and is beaten by something, so any
+    /// result can be reached against any move. Codes must be distinct within
+    /// their column, and neither empty nor holding a space, so every line of
+    /// a guide reads one way.
+    pub fn new(shapes: Vec<Shape>, beats: Vec<Vec<usize>>, outcome_scores: [i32; 3], result_codes: [String; 3]) -> std::result::Result<Rules, String> {
+        if beats.len() != shapes.len() {
+            return Err(format!("Expected what each of the {} moves beats, found {}", shapes.len(), beats.len()));
+        }
+        check_codes("opponent", shapes.iter().map(|s| (s.name.as_str(), s.enemy_code.as_str())))?;
+        check_codes("player", shapes.iter().map(|s| (s.name.as_str(), s.my_code.as_str())))?;
+        check_codes("result", ["Loss", "Draw", "Win"].into_iter().zip(result_codes.iter().map(String::as_str)))?;
+        let rules = Rules { shapes, beats, outcome_scores, result_codes };
+        let n = rules.shapes.len();
+        for a in 0..n {
+            let name = &rules.shapes[a].name;
+            if let Some(b) = rules.beats[a].iter().find(|&&b| b >= n) {
+                return Err(format!("{} beats move {}, but there are only {} moves", name, b, n));
+            }
+            if rules.beats[a].contains(&a) {
+                return Err(format!("{} beats itself", name));
+            }
+            for b in a + 1..n {
+                let (a_wins, b_wins) = (rules.beats[a].contains(&b), rules.beats[b].contains(&a));
+                if a_wins == b_wins {
+                    let problem = if a_wins { "beat each other" } else { "draw" };
+                    return Err(format!("{} and {} {}", name, rules.shapes[b].name, problem));
+                }
+            }
+            if rules.beats[a].is_empty() {
+                return Err(format!("{} beats nothing", name));
+            }
+            if !rules.beats.iter().any(|beaten| beaten.contains(&a)) {
+                return Err(format!("Nothing beats {}", name));
+            }
+        }
+        Ok(rules)
+    }
+
+    /// The balanced game over an odd number of moves, where each move beats
+    /// the moves an odd number of places before it in `names`, wrapping
+    /// around. Rock Paper Scissors Spock Lizard is the game over five.
+    ///
+    /// Moves score 1, 2, ... in order. The opponent's codes are `A`, `B`, ...
+    /// and the player's are the last letters of the alphabet, so three moves
+    /// get `X`, `Y` and `Z` as in the puzzle.
+    pub fn cyclic(names: &[&str]) -> std::result::Result<Rules, String> {
+        let n = names.len();
+        if n < 3 || n.is_multiple_of(2) || n > 13 {
+            return Err(format!("Cyclic games need an odd number of moves from 3 to 13, found {}", n));
+        }
+        let letter = |c: u8| char::from(c).to_string();
+        let shapes = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Shape {
+                name: name.to_string(),
+                score: i as i32 + 1,
+                enemy_code: letter(b'A' + i as u8),
+                my_code: letter(b'Z' + 1 - (n - i) as u8),
+            })
+            .collect();
+        let beats = (0..n).map(|a| (0..n).filter(|&b| (a + n - b) % n % 2 == 1).collect()).collect();
+        Rules::new(shapes, beats, [0, 3, 6], [letter(b'X'), letter(b'Y'), letter(b'Z')])
+    }
+
+    /// Rock Paper Scissors, as the puzzle plays it.
+    pub fn classic() -> &'static Rules {
+        &CLASSIC
+    }
+
+    pub fn rock_paper_scissors_spock_lizard() -> Rules {
+        Rules::cyclic(&["Rock", "Paper", "Scissors", "Spock", "Lizard"]).expect("Rock Paper Scissors Spock Lizard is a valid game")
+    }
+
+    pub fn enemy_move(&self, code: &str) -> Option<usize> {
+        self.shapes.iter().position(|shape| shape.enemy_code == code)
+    }
+
+    pub fn my_move(&self, code: &str) -> Option<usize> {
+        self.shapes.iter().position(|shape| shape.my_code == code)
+    }
+
+    pub fn result(&self, code: &str) -> Option<Result> {
+        self.result_codes.iter().position(|c| c == code).map(Result::from_index)
+    }
+
+    pub fn outcome(&self, mine: usize, theirs: usize) -> Result {
+        if mine == theirs {
+            Result::Draw
+        } else if self.beats[mine].contains(&theirs) {
+            Result::Win
+        } else {
+            Result::Loss
+        }
+    }
+
+    /// The first move getting `result` against `theirs`. `new` checks there
+    /// always is one.
+    pub fn move_for(&self, theirs: usize, result: Result) -> usize {
+        (0..self.shapes.len())
+            .find(|&mine| self.outcome(mine, theirs) == result)
+            .expect("every move has a move beating it and one it beats")
+    }
+
+    pub fn outcome_score(&self, result: Result) -> i32 {
+        self.outcome_scores[result as usize]
+    }
+
+    pub fn score(&self, mine: usize, theirs: usize) -> i32 {
+        self.shapes[mine].score + self.outcome_score(self.outcome(mine, theirs))
+    }
+
+    pub fn parse_enemy_move(&self, code: &str) -> std::result::Result<usize, ParseCodeError> {
+        self.enemy_move(code).ok_or_else(|| ParseCodeError::new(code, self.codes(|s| &s.enemy_code)))
+    }
+
+    pub fn parse_my_move(&self, code: &str) -> std::result::Result<usize, ParseCodeError> {
+        self.my_move(code).ok_or_else(|| ParseCodeError::new(code, self.codes(|s| &s.my_code)))
+    }
+
+    pub fn parse_result(&self, code: &str) -> std::result::Result<Result, ParseCodeError> {
+        self.result(code).ok_or_else(|| ParseCodeError::new(code, one_of(&self.result_codes.each_ref().map(String::as_str))))
+    }
+
+    /// Total score of a guide whose second column is the player's move.
+    pub fn score_moves(&self, lines: &mut Lines) -> std::result::Result<i32, AocError> {
+        let guide = Guide::parse_with(lines, false, |c| self.parse_enemy_move(c), |c| self.parse_my_move(c))?;
+        Ok(guide.rounds.iter().map(|round| self.score(round.second, round.enemy)).sum())
+    }
+
+    /// Total score of a guide whose second column is how the round must end.
+    pub fn score_results(&self, lines: &mut Lines) -> std::result::Result<i32, AocError> {
+        let guide = Guide::parse_with(lines, false, |c| self.parse_enemy_move(c), |c| self.parse_result(c))?;
+        Ok(guide.rounds.iter().map(|round| self.score(self.move_for(round.enemy, round.second), round.enemy)).sum())
+    }
+
+    fn codes(&self, code: impl Fn(&Shape) -> &String) -> String {
+        one_of(&self.shapes.iter().map(|shape| code(shape).as_str()).collect::<Vec<_>>())
+    }
+}
+
+/// Checks the `column` codes, each paired with the name of what it stands for.
+fn check_codes<'a>(column: &str, codes: impl Iterator<Item = (&'a str, &'a str)>) -> std::result::Result<(), String> {
+    let mut seen: Vec<(&str, &str)> = Vec::new();
+    for (name, code) in codes {
+        if code.is_empty() || code.contains(' ') {
+            return Err(format!("{}'s {} code `{}` must be non-empty and without spaces", name, column, code));
+        }
+        if let Some((other, _)) = seen.iter().find(|&&(_, c)| c == code) {
+            return Err(format!("{} and {} share the {} code `{}`", other, name, column, code));
+        }
+        seen.push((name, code));
+    }
+    Ok(())
+}
+
+/// `A, B or C`.
+fn one_of(codes: &[&str]) -> String {
+    match codes {
+        [] => String::new(),
+        [code] => code.to_string(),
+        [rest @ .., last] => format!("{} or {}", rest.join(", "), last),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Result {
+    Loss,
+    Draw,
+    Win
+}
+
+impl Result {
+    fn from_index(index: usize) -> Result {
+        [Result::Loss, Result::Draw, Result::Win][index]
+    }
+
+    pub fn get_value(&self) -> i32 {
+        Rules::classic().outcome_score(*self)
+    }
+}
+
+#[derive(Eq, Clone, Copy, Debug)]
+pub enum Moveset {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl PartialEq for Moveset {
+    fn eq(&self, other: &Self) -> bool {
+        self.get_value().eq(&other.get_value())
+    }
+}
+
+impl PartialOrd for Moveset {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders moves by which wins, so it is not transitive.
+impl Ord for Moveset {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match Rules::classic().outcome(self.index(), other.index()) {
+            Result::Loss => Ordering::Less,
+            Result::Draw => Ordering::Equal,
+            Result::Win => Ordering::Greater,
+        }
+    }
+}
+
+impl Moveset {
+    /// Index of the move in `Rules::classic()`.
+    pub fn index(&self) -> usize {
+        match self {
+            Moveset::Rock => 0,
+            Moveset::Paper => 1,
+            Moveset::Scissors => 2,
+        }
+    }
+
+    pub fn from_index(index: usize) -> Moveset {
+        [Moveset::Rock, Moveset::Paper, Moveset::Scissors][index]
+    }
+
+    pub fn get_move_from_result(&self, result: &Result) -> Moveset {
+        Moveset::from_index(Rules::classic().move_for(self.index(), *result))
+    }
+
+    pub fn get_value(&self) -> i32 {
+        Rules::classic().shapes[self.index()].score
+    }
+
+    pub fn get_result(&self, opponent: &Moveset) -> Result {
+        Rules::classic().outcome(self.index(), opponent.index())
+    }
+
+    pub fn get_total_score(&self, opponent: &Moveset) -> i32 {
+        Rules::classic().score(self.index(), opponent.index())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnemyMove {
+    A,
+    B,
+    C,
+}
+
+impl EnemyMove {
+    fn from_index(index: usize) -> EnemyMove {
+        [EnemyMove::A, EnemyMove::B, EnemyMove::C][index]
+    }
+
+    pub fn as_move(&self) -> Moveset {
+        Moveset::from_index(*self as usize)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MyMove {
+    X,
+    Y,
+    Z,
+}
+
+impl MyMove {
+    fn from_index(index: usize) -> MyMove {
+        [MyMove::X, MyMove::Y, MyMove::Z][index]
+    }
+
+    pub fn as_move(&self) -> Moveset {
+        Moveset::from_index(*self as usize)
+    }
+}
+
+/// A code in a guide that is not one of the codes expected there.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseCodeError {
+    pub code: String,
+    /// The codes allowed, like `A, B or C`.
+    pub expected: String,
+}
+
+impl ParseCodeError {
+    fn new(code: &str, expected: String) -> ParseCodeError {
+        ParseCodeError { code: code.to_string(), expected }
+    }
+}
+
+impl fmt::Display for ParseCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Expected {}, found `{}`", self.expected, self.code)
+    }
+}
+
+impl std::error::Error for ParseCodeError {}
+
+impl FromStr for EnemyMove {
+    type Err = ParseCodeError;
+
+    fn from_str(s: &str) -> std::result::Result<EnemyMove, ParseCodeError> {
+        Rules::classic().parse_enemy_move(s).map(EnemyMove::from_index)
+    }
+}
+
+impl FromStr for MyMove {
+    type Err = ParseCodeError;
+
+    fn from_str(s: &str) -> std::result::Result<MyMove, ParseCodeError> {
+        Rules::classic().parse_my_move(s).map(MyMove::from_index)
+    }
+}
+
+impl FromStr for Result {
+    type Err = ParseCodeError;
+
+    fn from_str(s: &str) -> std::result::Result<Result, ParseCodeError> {
+        Rules::classic().parse_result(s)
+    }
+}
+
+/// A line of the guide: the opponent's move and the second column, read as a
+/// `MyMove` in part 1 and a `Result` in part 2. Games other than the puzzle's
+/// read moves as indices into their `Rules`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Round<T, E = EnemyMove> {
+    pub enemy: E,
+    pub second: T,
+}
+
+impl<T, E> Round<T, E> {
+    /// Reads a line like `A Y`, two codes separated by a space, decoding each
+    /// column with its function.
+    pub fn parse_with(
+        index: usize,
+        line: &str,
+        enemy: impl Fn(&str) -> std::result::Result<E, ParseCodeError>,
+        second: impl Fn(&str) -> std::result::Result<T, ParseCodeError>,
+    ) -> std::result::Result<Round<T, E>, AocError> {
+        let codes: Vec<_> = line.split(' ').collect();
+        let [raw_enemy, raw_second] = codes[..] else {
+            return Err(AocError::line(index, line, "Expected two codes separated by a space, like `A Y`"));
+        };
+        let error = |code: &str, e: ParseCodeError| AocError::at(index, line, code, e.to_string());
+        Ok(Round { enemy: enemy(raw_enemy).map_err(|e| error(raw_enemy, e))?, second: second(raw_second).map_err(|e| error(raw_second, e))? })
+    }
+}
+
+impl<T: FromStr<Err = ParseCodeError>> Round<T> {
+    pub fn parse(index: usize, line: &str) -> std::result::Result<Round<T>, AocError> {
+        Round::parse_with(index, line, str::parse, str::parse)
+    }
+}
+
+/// The rounds of a guide, and the lines skipped when read leniently.
+#[derive(Clone, Debug)]
+pub struct Guide<T, E = EnemyMove> {
+    pub rounds: Vec<Round<T, E>>,
+    pub skipped: Vec<AocError>,
+}
+
+impl<T, E> Guide<T, E> {
+    /// Fails on the first unreadable line, or with `lenient` skips it and
+    /// keeps its error.
+    pub fn parse_with(
+        lines: &mut Lines,
+        lenient: bool,
+        enemy: impl Fn(&str) -> std::result::Result<E, ParseCodeError>,
+        second: impl Fn(&str) -> std::result::Result<T, ParseCodeError>,
+    ) -> std::result::Result<Guide<T, E>, AocError> {
+        let mut guide = Guide { rounds: Vec::new(), skipped: Vec::new() };
+        for (index, line) in lines.enumerate() {
+            match Round::parse_with(index, line, &enemy, &second) {
+                Ok(round) => guide.rounds.push(round),
+                Err(e) if lenient => guide.skipped.push(e),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(guide)
+    }
+}
+
+impl<T: FromStr<Err = ParseCodeError>> Guide<T> {
+    pub fn parse(lines: &mut Lines, lenient: bool) -> std::result::Result<Guide<T>, AocError> {
+        Guide::parse_with(lines, lenient, str::parse, str::parse)
+    }
+}
+
+impl Guide<MyMove> {
+    /// Total score of a guide read as part 1 reads it, X, Y and Z being moves.
+    pub fn score(&self) -> i32 {
+        self.rounds.iter().map(|round| round.second.as_move().get_total_score(&round.enemy.as_move())).sum()
+    }
+}
+
+impl Guide<Result> {
+    /// Total score of a guide read as part 2 reads it, X, Y and Z being results.
+    pub fn score(&self) -> i32 {
+        let score = |round: &Round<Result>| {
+            let enemy_move = round.enemy.as_move();
+            enemy_move.get_move_from_result(&round.second).get_total_score(&enemy_move)
+        };
+        self.rounds.iter().map(score).sum()
+    }
+}
+
+pub fn part1(lines: &mut Lines) -> std::result::Result<i32, AocError> {
+    Ok(Guide::<MyMove>::parse(lines, false)?.score())
+}
+
+pub fn part2(lines: &mut Lines) -> std::result::Result<i32, AocError> {
+    Ok(Guide::<Result>::parse(lines, false)?.score())
+}
+
+/// Every order of three things.
+const PERMUTATIONS: [[usize; 3]; 6] = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+
+/// What X, Y and Z could mean: the move to play, or how the round must end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mapping {
+    Moves([Moveset; 3]),
+    Results([Result; 3]),
+}
+
+impl fmt::Display for Mapping {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let meanings = match self {
+            Mapping::Moves(moves) => moves.map(|m| format!("{:?}", m)),
+            Mapping::Results(results) => results.map(|r| format!("{:?}", r)),
+        };
+        write!(f, "X={} Y={} Z={}", meanings[0], meanings[1], meanings[2])
+    }
+}
+
+/// The score of a guide under every way of reading X, Y and Z, as moves and
+/// as results, best first.
+#[derive(Clone, Debug)]
+pub struct Analysis {
+    pub scores: Vec<(Mapping, i32)>,
+}
+
+impl Analysis {
+    pub fn new(lines: &mut Lines) -> std::result::Result<Analysis, AocError> {
+        // How many rounds have each opponent move and second column, so each
+        // mapping is scored without going through the guide again.
+        let mut counts = [[0; 3]; 3];
+        for (index, line) in lines.enumerate() {
+            let round = Round::<MyMove>::parse(index, line)?;
+            counts[round.enemy as usize][round.second as usize] += 1;
+        }
+
+        let score = |round_score: &dyn Fn(Moveset, usize) -> i32| -> i32 {
+            (0..3).map(|enemy| (0..3).map(|second| counts[enemy][second] * round_score(Moveset::from_index(enemy), second)).sum::<i32>()).sum()
+        };
+        let mut scores = Vec::new();
+        for order in PERMUTATIONS {
+            let moves = order.map(Moveset::from_index);
+            scores.push((Mapping::Moves(moves), score(&|enemy, second| moves[second].get_total_score(&enemy))));
+        }
+        for order in PERMUTATIONS {
+            let results = order.map(Result::from_index);
+            let round_score = |enemy: Moveset, second: usize| enemy.get_move_from_result(&results[second]).get_total_score(&enemy);
+            scores.push((Mapping::Results(results), score(&round_score)));
+        }
+        scores.sort_by_key(|&(_, score)| Reverse(score));
+        Ok(Analysis { scores })
+    }
+
+    pub fn best(&self) -> Option<(Mapping, i32)> {
+        self.scores.first().copied()
+    }
+
+    pub fn worst(&self) -> Option<(Mapping, i32)> {
+        self.scores.last().copied()
+    }
+
+    /// Every mapping's score, the best and worst, and how the scores spread,
+    /// as printed by `aoc2022-day2 analyze`.
+    pub fn report(&self) -> String {
+        let (Some((best, best_score)), Some((worst, worst_score))) = (self.best(), self.worst()) else {
+            return String::from("No mappings\n");
+        };
+        let mut report = String::new();
+        for (mapping, score) in &self.scores {
+            report.push_str(&format!("{:<9}{:<32}{}\n", kind(mapping), mapping.to_string(), score));
+        }
+        let mean = self.scores.iter().map(|&(_, score)| score as f64).sum::<f64>() / self.scores.len() as f64;
+        let puzzle = |mapping| self.scores.iter().find(|(m, _)| *m == mapping).map_or(0, |&(_, score)| score);
+        report.push_str(&format!("\nBest:\t\t{} {} ({})\n", kind(&best), best, best_score));
+        report.push_str(&format!("Worst:\t\t{} {} ({})\n", kind(&worst), worst, worst_score));
+        report.push_str(&format!("Mean:\t\t{:.1}\n", mean));
+        report.push_str(&format!("Range:\t\t{}\n", best_score - worst_score));
+        report.push_str(&format!("Part 1:\t\t{}\n", puzzle(Mapping::Moves(PERMUTATIONS[0].map(Moveset::from_index)))));
+        report.push_str(&format!("Part 2:\t\t{}\n", puzzle(Mapping::Results(PERMUTATIONS[0].map(Result::from_index)))));
+        report
+    }
+}
+
+fn kind(mapping: &Mapping) -> &'static str {
+    match mapping {
+        Mapping::Moves(_) => "moves",
+        Mapping::Results(_) => "results",
+    }
+}
+
+const MOVES: [Moveset; 3] = [Moveset::Rock, Moveset::Paper, Moveset::Scissors];
+
+/// A way of choosing moves in a match of Rock Paper Scissors.
+pub trait Strategy {
+    fn name(&self) -> String;
+
+    /// The next move, knowing every earlier move of the match, in order.
+    fn play(&mut self, mine: &[Moveset], theirs: &[Moveset]) -> Moveset;
+}
+
+/// Always plays the same move.
+pub struct Always(pub Moveset);
+
+impl Strategy for Always {
+    fn name(&self) -> String {
+        format!("always {:?}", self.0).to_lowercase()
+    }
+
+    fn play(&mut self, _: &[Moveset], _: &[Moveset]) -> Moveset {
+        self.0
+    }
+}
+
+/// Plays moves at random.
+pub struct Random {
+    rng: Rng,
+    seed: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Random {
+        Random { rng: Rng::new(seed), seed }
+    }
+}
+
+impl Strategy for Random {
+    fn name(&self) -> String {
+        format!("random (seed {})", self.seed)
+    }
+
+    fn play(&mut self, _: &[Moveset], _: &[Moveset]) -> Moveset {
+        *self.rng.pick(&MOVES)
+    }
+}
+
+/// Plays what the opponent played last, starting with rock.
+pub struct CopyLast;
+
+impl Strategy for CopyLast {
+    fn name(&self) -> String {
+        String::from("copy last")
+    }
+
+    fn play(&mut self, _: &[Moveset], theirs: &[Moveset]) -> Moveset {
+        theirs.last().copied().unwrap_or(Moveset::Rock)
+    }
+}
+
+/// Plays what beats the opponent's last move, starting with rock.
+pub struct BeatLast;
+
+impl Strategy for BeatLast {
+    fn name(&self) -> String {
+        String::from("beat last")
+    }
+
+    fn play(&mut self, _: &[Moveset], theirs: &[Moveset]) -> Moveset {
+        theirs.last().map_or(Moveset::Rock, |last| last.get_move_from_result(&Result::Win))
+    }
+}
+
+/// Plays what beats the opponent's most frequent move so far, starting with
+/// rock.
+pub struct FrequencyCounter;
+
+impl Strategy for FrequencyCounter {
+    fn name(&self) -> String {
+        String::from("frequency counter")
+    }
+
+    fn play(&mut self, _: &[Moveset], theirs: &[Moveset]) -> Moveset {
+        let mut counts = [0; 3];
+        theirs.iter().for_each(|m| counts[m.index()] += 1);
+        match (0..3).max_by_key(|&i| (counts[i], Reverse(i))) {
+            Some(i) if !theirs.is_empty() => Moveset::from_index(i).get_move_from_result(&Result::Win),
+            _ => Moveset::Rock,
+        }
+    }
+}
+
+/// Plays rock, paper, scissors, rock and so on.
+pub struct Cycle;
+
+impl Strategy for Cycle {
+    fn name(&self) -> String {
+        String::from("cycle")
+    }
+
+    fn play(&mut self, mine: &[Moveset], _: &[Moveset]) -> Moveset {
+        MOVES[mine.len() % MOVES.len()]
+    }
+}
+
+/// Every built-in strategy, with random play seeded by `seed`.
+pub fn strategies(seed: u64) -> Vec<Box<dyn Strategy>> {
+    vec![
+        Box::new(Always(Moveset::Rock)),
+        Box::new(Always(Moveset::Paper)),
+        Box::new(Random::new(seed)),
+        Box::new(CopyLast),
+        Box::new(BeatLast),
+        Box::new(FrequencyCounter),
+        Box::new(Cycle),
+    ]
+}
+
+/// How a strategy did over a tournament.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Standing {
+    pub name: String,
+    pub score: i64,
+    pub matches_won: usize,
+    pub matches_drawn: usize,
+    pub matches_lost: usize,
+    pub rounds_won: usize,
+    pub rounds_drawn: usize,
+    pub rounds_lost: usize,
+}
+
+/// Plays every strategy against every other for `rounds` rounds, scoring each
+/// round as the guide does. The standings are sorted by total score, and a
+/// match is won by scoring more over it.
+pub fn tournament(players: &mut [Box<dyn Strategy>], rounds: usize) -> Vec<Standing> {
+    let mut standings: Vec<Standing> = players.iter().map(|p| Standing { name: p.name(), ..Standing::default() }).collect();
+    for a in 0..players.len() {
+        for b in a + 1..players.len() {
+            let (left, right) = players.split_at_mut(b);
+            let (player_a, player_b) = (&mut left[a], &mut right[0]);
+            let (mut moves_a, mut moves_b) = (Vec::with_capacity(rounds), Vec::with_capacity(rounds));
+            let (mut score_a, mut score_b) = (0, 0);
+            for _ in 0..rounds {
+                let move_a = player_a.play(&moves_a, &moves_b);
+                let move_b = player_b.play(&moves_b, &moves_a);
+                score_a += move_a.get_total_score(&move_b) as i64;
+                score_b += move_b.get_total_score(&move_a) as i64;
+                match move_a.get_result(&move_b) {
+                    Result::Win => (standings[a].rounds_won += 1, standings[b].rounds_lost += 1),
+                    Result::Draw => (standings[a].rounds_drawn += 1, standings[b].rounds_drawn += 1),
+                    Result::Loss => (standings[a].rounds_lost += 1, standings[b].rounds_won += 1),
+                };
+                moves_a.push(move_a);
+                moves_b.push(move_b);
+            }
+
+            standings[a].score += score_a;
+            standings[b].score += score_b;
+            match score_a.cmp(&score_b) {
+                Ordering::Greater => (standings[a].matches_won += 1, standings[b].matches_lost += 1),
+                Ordering::Equal => (standings[a].matches_drawn += 1, standings[b].matches_drawn += 1),
+                Ordering::Less => (standings[a].matches_lost += 1, standings[b].matches_won += 1),
+            };
+        }
+    }
+    standings.sort_by_key(|standing| Reverse(standing.score));
+    standings
+}
+
+/// The standings as a table, as printed by `aoc2022-day2 tournament`.
+pub fn leaderboard(standings: &[Standing]) -> String {
+    let width = standings.iter().map(|s| s.name.len()).max().unwrap_or(0).max("Strategy".len());
+    let mut table = format!("   {:<width$}  {:>8}  {:>15}  {:>18}\n", "Strategy", "Score", "Matches (W/D/L)", "Rounds (W/D/L)");
+    for (rank, s) in standings.iter().enumerate() {
+        let matches = format!("{}/{}/{}", s.matches_won, s.matches_drawn, s.matches_lost);
+        let rounds = format!("{}/{}/{}", s.rounds_won, s.rounds_drawn, s.rounds_lost);
+        table.push_str(&format!("{:>2} {:<width$}  {:>8}  {:>15}  {:>18}\n", rank + 1, s.name, s.score, matches, rounds));
+    }
+    table
+}
+
+/// `size` rounds of the strategy guide.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| format!("{} {}\n", rng.pick(&["A", "B", "C"]), rng.pick(&["X", "Y", "Z"]))).collect()
+}
+
+harness::solution! {
+    year: 2022,
+    day: 2,
+    title: "Rock Paper Scissors",
+    tags: ["parsing"],
+    algorithms: ["lookup table"],
+    difficulty: 1,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+A Y
+B X
+C Z" => { part1: 15, part2: 12 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shape(name: &str, enemy_code: &str, my_code: &str) -> Shape {
+        Shape { name: name.to_string(), score: 1, enemy_code: enemy_code.to_string(), my_code: my_code.to_string() }
+    }
+
+    #[test]
+    fn scores_rock_paper_scissors_spock_lizard() {
+        let rules = Rules::rock_paper_scissors_spock_lizard();
+        // Rock draws Rock, Lizard draws Lizard, Scissors loses to Spock,
+        // Paper draws Paper.
+        assert_eq!(rules.score_moves(&mut "A V\nE Z\nD X\nB W".lines()), Ok(4 + 8 + 3 + 5));
+        // Scissors loses to Rock, Rock beats Lizard, Spock draws Spock.
+        assert_eq!(rules.score_results(&mut "A X\nE Z\nD Y".lines()), Ok(3 + 7 + 7));
+    }
+
+    #[test]
+    fn rejects_games_where_a_result_cannot_be_reached() {
+        assert!(Rules::cyclic(&["Rock"]).is_err());
+        let codes = [String::from("X"), String::from("Y"), String::from("Z")];
+        let shapes = vec![shape("A", "A", "X"), shape("B", "B", "Y"), shape("C", "C", "Z")];
+        let transitive = Rules::new(shapes, vec![vec![1, 2], vec![2], vec![]], [0, 3, 6], codes);
+        assert_eq!(transitive.err(), Some(String::from("Nothing beats A")));
+    }
+
+    #[test]
+    fn rejects_codes_that_do_not_read_one_way() {
+        let codes = |x: &str, y: &str, z: &str| [x.to_string(), y.to_string(), z.to_string()];
+        let rules = |shapes: Vec<Shape>, result_codes| Rules::new(shapes, vec![vec![2], vec![0], vec![1]], [0, 3, 6], result_codes).err();
+        let valid = || vec![shape("Rock", "A", "X"), shape("Paper", "B", "Y"), shape("Scissors", "C", "Z")];
+        assert_eq!(rules(valid(), codes("X", "Y", "Z")), None);
+
+        let mut shapes = valid();
+        shapes[2].enemy_code = String::from("A");
+        assert_eq!(rules(shapes, codes("X", "Y", "Z")), Some(String::from("Rock and Scissors share the opponent code `A`")));
+        let mut shapes = valid();
+        shapes[1].my_code = String::new();
+        assert_eq!(rules(shapes, codes("X", "Y", "Z")), Some(String::from("Paper's player code `` must be non-empty and without spaces")));
+        let mut shapes = valid();
+        shapes[0].my_code = String::from("X 1");
+        assert_eq!(rules(shapes, codes("X", "Y", "Z")), Some(String::from("Rock's player code `X 1` must be non-empty and without spaces")));
+        assert_eq!(rules(valid(), codes("X", "Y", "X")), Some(String::from("Loss and Win share the result code `X`")));
+        assert_eq!(rules(valid(), codes("X", "", "Z")), Some(String::from("Draw's result code `` must be non-empty and without spaces")));
+    }
+
+    #[test]
+    fn analyzes_every_reading_of_the_example() {
+        let input = harness::testing::example_input(crate::example_tests::example::INPUT);
+        let analysis = Analysis::new(&mut input.lines()).unwrap();
+        assert_eq!(analysis.scores.len(), 12);
+        let score = |mapping| analysis.scores.iter().find(|&&(m, _)| m == mapping).map(|&(_, score)| score);
+        assert_eq!(score(Mapping::Moves([Moveset::Rock, Moveset::Paper, Moveset::Scissors])), Some(15));
+        assert_eq!(score(Mapping::Results([Result::Loss, Result::Draw, Result::Win])), Some(12));
+        let best = Mapping::Moves([Moveset::Scissors, Moveset::Paper, Moveset::Rock]);
+        assert_eq!(analysis.best(), Some((best, 24)));
+        let worst = Mapping::Moves([Moveset::Rock, Moveset::Scissors, Moveset::Paper]);
+        assert_eq!(analysis.worst(), Some((worst, 6)));
+    }
+
+    #[test]
+    fn ranks_a_tournament_by_total_score() {
+        let mut players: Vec<Box<dyn Strategy>> = MOVES.map(|m| Box::new(Always(m)) as Box<dyn Strategy>).into();
+        let standings = tournament(&mut players, 10);
+        // Each beats one of the others every round: paper scores 8 a round
+        // against rock, scissors 9 against paper and rock 7 against scissors.
+        let standing = |name: &str, score| Standing {
+            name: name.to_string(),
+            score,
+            matches_won: 1,
+            matches_lost: 1,
+            rounds_won: 10,
+            rounds_lost: 10,
+            ..Standing::default()
+        };
+        assert_eq!(standings, [standing("always scissors", 90 + 30), standing("always paper", 80 + 20), standing("always rock", 10 + 70)]);
+        assert_eq!(
+            leaderboard(&standings),
+            concat!(
+                "   Strategy            Score  Matches (W/D/L)      Rounds (W/D/L)\n",
+                " 1 always scissors       120            1/0/1             10/0/10\n",
+                " 2 always paper          100            1/0/1             10/0/10\n",
+                " 3 always rock            80            1/0/1             10/0/10\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use std::{env, path::Path};
+
+use aoc2022_day2::{Guide, MyMove, Result};
+
+const DEFAULT_ROUNDS: usize = 1000;
+const DEFAULT_SEED: u64 = 1;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("analyze") => analyze(args.get(1).map(Path::new)),
+        Some("score") => score(&args[1..]),
+        Some("tournament") => tournament(args.get(1).map(String::as_str), args.get(2).map(String::as_str)),
+        _ => aoc2022_day2::SOLUTION.run(),
+    }
+}
+
+/// Prints how the guide at `path`, or on stdin, scores under every reading of
+/// X, Y and Z.
+fn analyze(path: Option<&Path>) {
+    let analysis = harness::parse_input(path, |input| aoc2022_day2::Analysis::new(&mut input.lines()));
+    print!("{}", analysis.report());
+}
+
+/// Prints both parts' scores of the guide at the path given, or on stdin.
+/// With `--lenient`, unreadable lines are skipped and listed on stderr rather
+/// than stopping at the first.
+fn score(args: &[String]) {
+    const USAGE: &str = "Usage: aoc2022-day2 score [<path>] [--lenient]";
+    let mut path = None;
+    let mut lenient = false;
+    for arg in args {
+        match arg.as_str() {
+            "--lenient" => lenient = true,
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(Path::new(arg)),
+            _ => harness::fail(USAGE),
+        }
+    }
+
+    let (moves, results) = harness::parse_input(path, |input| {
+        Ok((Guide::<MyMove>::parse(&mut input.lines(), lenient)?, Guide::<Result>::parse(&mut input.lines(), lenient)?))
+    });
+    if !moves.skipped.is_empty() {
+        eprintln!("Skipped {} unreadable lines", moves.skipped.len());
+        for e in &moves.skipped {
+            eprintln!("{}", path.map_or(e.clone(), |path| e.clone().with_path(path)));
+        }
+    }
+    println!("Part 1:\t{}", moves.score());
+    println!("Part 2:\t{}", results.score());
+}
+
+/// Plays the built-in strategies against each other and prints the
+/// leaderboard.
+fn tournament(rounds: Option<&str>, seed: Option<&str>) {
+    const USAGE: &str = "Usage: aoc2022-day2 tournament [<rounds>] [<seed>]";
+    let rounds = rounds.map_or(Ok(DEFAULT_ROUNDS), str::parse).unwrap_or_else(|_| harness::fail(USAGE));
+    let seed = seed.map_or(Ok(DEFAULT_SEED), str::parse).unwrap_or_else(|_| harness::fail(USAGE));
+    let mut players = aoc2022_day2::strategies(seed);
+    let standings = aoc2022_day2::tournament(&mut players, rounds);
+    print!("{}", aoc2022_day2::leaderboard(&standings));
+}
@@ -0,0 +1,366 @@
+use std::num::NonZeroUsize;
+use std::str::Lines;
+
+use harness::{generate::Rng, AocError};
+
+pub const GROUP_SIZE: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+/// Priority of an item: 1 to 26 for a-z, 27 to 52 for A-Z.
+pub fn priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+/// A set of item types, with bit `p` set for the item of priority `p`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Items(pub u64);
+
+impl Items {
+    pub const NONE: Items = Items(0);
+    pub const ALL: Items = Items(((1 << 52) - 1) << 1);
+
+    /// The item types in `items`, ignoring anything that is not an item.
+    pub fn from_items(items: &str) -> Items {
+        Items(items.chars().filter_map(priority).fold(0, |set, p| set | 1 << p))
+    }
+
+    pub fn intersect(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Priorities of the items, lowest first.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |p| self.0 & 1 << p != 0)
+    }
+
+    pub fn items(self) -> impl Iterator<Item = char> {
+        self.priorities().map(|p| match p {
+            1..=26 => (b'a' + p as u8 - 1) as char,
+            _ => (b'A' + p as u8 - 27) as char,
+        })
+    }
+
+    pub fn priority_sum(self) -> u32 {
+        self.priorities().sum()
+    }
+}
+
+/// Items in both compartments of a rucksack checked by `parse_rucksack`. A
+/// rucksack that cannot be split in half has none.
+fn compartments_shared(rucksack: &str) -> Items {
+    match rucksack.split_at_checked(rucksack.len() / 2) {
+        Some((first, second)) => Items::from_items(first).intersect(Items::from_items(second)),
+        None => Items::NONE,
+    }
+}
+
+/// Items in every one of `rucksacks`, or none when there are none.
+pub fn shared<S: AsRef<str>>(rucksacks: &[S]) -> Items {
+    match rucksacks {
+        [] => Items::NONE,
+        _ => rucksacks.iter().fold(Items::ALL, |items, r| items.intersect(Items::from_items(r.as_ref()))),
+    }
+}
+
+/// Items shared by each group of `size` consecutive rucksacks, reading the
+/// input once without keeping the rucksacks.
+pub fn group_badges(lines: &mut Lines, size: NonZeroUsize) -> Result<Vec<Items>, AocError> {
+    let mut badges = Vec::new();
+    let (mut group, mut count, mut next_index) = (Items::ALL, 0, 0);
+    for (index, line) in lines.enumerate() {
+        group = group.intersect(Items::from_items(parse_rucksack(index, line)?));
+        count += 1;
+        next_index = index + 1;
+        if count == size.get() {
+            badges.push(group);
+            (group, count) = (Items::ALL, 0);
+        }
+    }
+    if count > 0 {
+        return Err(AocError::end_of_input(next_index, format!("Expected groups of {} rucksacks", size)));
+    }
+    Ok(badges)
+}
+
+/// Checks a rucksack only holds letters and splits evenly into compartments.
+pub fn parse_rucksack(index: usize, line: &str) -> Result<&str, AocError> {
+    if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        return Err(AocError::at(index, line, &line[i..i + c.len_utf8()], format!("Expected an item from a-z or A-Z, found `{}`", c)));
+    }
+    if !line.len().is_multiple_of(2) {
+        return Err(AocError::line(index, line, format!("Expected an even number of items, found {}", line.len())));
+    }
+    Ok(line)
+}
+
+pub fn part1(lines: &mut Lines) -> Result<u32, AocError> {
+    let mut total_priority = 0;
+    for (index, line) in lines.enumerate() {
+        total_priority += compartments_shared(parse_rucksack(index, line)?).priority_sum();
+    }
+
+    Ok(total_priority)
+}
+
+pub fn part2(lines: &mut Lines) -> Result<u32, AocError> {
+    Ok(group_badges(lines, GROUP_SIZE)?.into_iter().map(Items::priority_sum).sum())
+}
+
+/// What the audit found in one rucksack.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RucksackAudit {
+    /// 0-based line of the rucksack.
+    pub line: usize,
+    pub items: Items,
+    /// Items in both compartments, where there should be exactly one.
+    pub duplicates: Items,
+    pub flags: Vec<String>,
+}
+
+/// What the audit found in one group of rucksacks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupAudit {
+    /// 0-based lines of the group's first and last rucksacks.
+    pub lines: (usize, usize),
+    /// Items every rucksack in the group has, where there should be exactly
+    /// one.
+    pub badges: Items,
+    pub flags: Vec<String>,
+}
+
+/// Every rucksack and group of an input checked for what the puzzle promises:
+/// compartments of equal size sharing one item, and groups sharing one badge.
+/// Unlike the parts, it carries on past bad rucksacks, so a hand-edited or
+/// generated input can be checked in one go.
+#[derive(Clone, Debug, Default)]
+pub struct Audit {
+    pub rucksacks: Vec<RucksackAudit>,
+    pub groups: Vec<GroupAudit>,
+}
+
+impl Audit {
+    pub fn new(lines: &mut Lines, group_size: NonZeroUsize) -> Audit {
+        let mut audit = Audit::default();
+        for (index, line) in lines.enumerate() {
+            let mut flags = Vec::new();
+            if let Some(c) = line.chars().find(|&c| priority(c).is_none()) {
+                flags.push(format!("holds `{}`, which is not an item", c));
+            }
+            if !line.len().is_multiple_of(2) {
+                flags.push(format!("has an odd number of items ({})", line.len()));
+            }
+            let duplicates = compartments_shared(line);
+            match duplicates.len() {
+                0 => flags.push(String::from("has no item in both compartments")),
+                1 => {}
+                n => flags.push(format!("has {} items in both compartments", n)),
+            }
+            audit.rucksacks.push(RucksackAudit { line: index, items: Items::from_items(line), duplicates, flags });
+        }
+
+        for group in audit.rucksacks.chunks(group_size.get()) {
+            let mut flags = Vec::new();
+            if group.len() < group_size.get() {
+                flags.push(format!("has {} of {} rucksacks", group.len(), group_size));
+            }
+            let badges = group.iter().fold(Items::ALL, |badges, rucksack| badges.intersect(rucksack.items));
+            match badges.len() {
+                0 => flags.push(String::from("shares no item")),
+                1 => {}
+                n => flags.push(format!("shares {} items", n)),
+            }
+            let lines = (group[0].line, group[group.len() - 1].line);
+            audit.groups.push(GroupAudit { lines, badges, flags });
+        }
+        audit
+    }
+
+    /// Whether anything was flagged.
+    pub fn is_clean(&self) -> bool {
+        self.rucksacks.iter().all(|r| r.flags.is_empty()) && self.groups.iter().all(|g| g.flags.is_empty())
+    }
+
+    /// Every rucksack's duplicate and every group's badge with their
+    /// priorities, then everything flagged, as printed by
+    /// `aoc2022-day3 audit`.
+    pub fn report(&self) -> String {
+        let describe = |items: Items| match items.len() {
+            0 => (String::from("-"), String::from("-")),
+            _ => (items.items().collect(), items.priorities().map(|p| p.to_string()).collect::<Vec<_>>().join("+")),
+        };
+
+        let mut report = format!("{:>6}  {:<10} {}\n", "Line", "Duplicate", "Priority");
+        for rucksack in &self.rucksacks {
+            let (items, priorities) = describe(rucksack.duplicates);
+            report.push_str(&format!("{:>6}  {:<10} {}\n", rucksack.line + 1, items, priorities));
+        }
+        report.push_str(&format!("\n{:>13}  {:<10} {}\n", "Lines", "Badge", "Priority"));
+        for group in &self.groups {
+            let (items, priorities) = describe(group.badges);
+            let lines = format!("{}-{}", group.lines.0 + 1, group.lines.1 + 1);
+            report.push_str(&format!("{:>13}  {:<10} {}\n", lines, items, priorities));
+        }
+
+        let flagged_rucksacks: Vec<_> = self.rucksacks.iter().filter(|r| !r.flags.is_empty()).collect();
+        let flagged_groups: Vec<_> = self.groups.iter().filter(|g| !g.flags.is_empty()).collect();
+        report.push_str(&format!("\nFlagged:\t{} rucksacks, {} groups\n", flagged_rucksacks.len(), flagged_groups.len()));
+        for rucksack in flagged_rucksacks {
+            for flag in &rucksack.flags {
+                report.push_str(&format!("  line {}: rucksack {}\n", rucksack.line + 1, flag));
+            }
+        }
+        for group in flagged_groups {
+            for flag in &group.flags {
+                report.push_str(&format!("  lines {}-{}: group {}\n", group.lines.0 + 1, group.lines.1 + 1, flag));
+            }
+        }
+        report
+    }
+}
+
+/// `size` rucksacks, rounded up to whole groups of three. Every rucksack has
+/// one item type in both compartments and every group shares one badge.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.div_ceil(3) {
+        let mut items: Vec<char> = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ".chars().collect();
+        rng.shuffle(&mut items);
+        let badge = items[0];
+        // Each elf draws from its own 17 items so the badge is the only one they share.
+        for pool in items[1..].chunks(17) {
+            let shared = if rng.chance(0.1) { badge } else { pool[0] };
+            let (left, right) = pool[1..].split_at(8);
+            let half = rng.range(2..=16) as usize;
+            let mut first = vec![shared];
+            let mut second = vec![shared];
+            if shared != badge {
+                if rng.chance(0.5) { first.push(badge) } else { second.push(badge) }
+            }
+            while first.len() < half {
+                first.push(*rng.pick(left));
+            }
+            while second.len() < half {
+                second.push(*rng.pick(right));
+            }
+            rng.shuffle(&mut first);
+            rng.shuffle(&mut second);
+            out.extend(first);
+            out.extend(second);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+harness::solution! {
+    year: 2022,
+    day: 3,
+    title: "Rucksack Reorganization",
+    tags: ["parsing", "sets"],
+    algorithms: ["bitset intersection"],
+    difficulty: 1,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw" => { part1: 157, part2: 70 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn size(n: usize) -> NonZeroUsize {
+        NonZeroUsize::new(n).unwrap()
+    }
+
+    #[test]
+    fn keeps_item_types_as_a_set() {
+        let items = Items::from_items("zaAa!");
+        assert_eq!(items.items().collect::<String>(), "azA");
+        assert_eq!(items.priorities().collect::<Vec<_>>(), [1, 26, 27]);
+        assert_eq!((items.len(), items.priority_sum()), (3, 54));
+        assert_eq!(items.intersect(Items::from_items("AZ")), Items::from_items("A"));
+        assert!(Items::NONE.is_empty());
+        assert_eq!(Items::ALL.len(), 52);
+    }
+
+    #[test]
+    fn finds_items_shared_by_every_rucksack() {
+        assert_eq!(shared(&["abcd", "bcde", "cdef"]), Items::from_items("cd"));
+        assert_eq!(shared(&["ab", "cd"]), Items::NONE);
+        assert_eq!(shared::<&str>(&[]), Items::NONE);
+    }
+
+    #[test]
+    fn finds_badges_of_any_group_size() {
+        let input = "abcd\nbxyz\ncdef\nCcWW";
+        assert_eq!(group_badges(&mut input.lines(), size(2)), Ok(vec![Items::from_items("b"), Items::from_items("c")]));
+        assert_eq!(group_badges(&mut input.lines(), size(4)), Ok(vec![Items::NONE]));
+        assert_eq!(group_badges(&mut input.lines(), size(1)).map(|badges| badges.len()), Ok(4));
+    }
+
+    #[test]
+    fn rejects_a_partial_last_group() {
+        let error = group_badges(&mut "abcd\nbxyz\ncdef".lines(), size(2)).unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (4, "Expected groups of 2 rucksacks"));
+    }
+
+    #[test]
+    fn audits_the_example_clean() {
+        let input = harness::testing::example_input(crate::example_tests::example::INPUT);
+        assert!(Audit::new(&mut input.lines(), GROUP_SIZE).is_clean());
+    }
+
+    #[test]
+    fn flags_rucksacks_and_groups_by_line() {
+        let audit = Audit::new(&mut "abca\nabcab\nab1b\nxyzw\naXaY".lines(), size(2));
+        let flags: Vec<_> = audit.rucksacks.iter().map(|r| (r.line, r.flags.clone())).collect();
+        assert_eq!(
+            flags,
+            [
+                (0, vec![]),
+                (1, vec![String::from("has an odd number of items (5)"), String::from("has 2 items in both compartments")]),
+                (2, vec![String::from("holds `1`, which is not an item")]),
+                (3, vec![String::from("has no item in both compartments")]),
+                (4, vec![]),
+            ]
+        );
+        let flags: Vec<_> = audit.groups.iter().map(|g| (g.lines, g.flags.clone())).collect();
+        assert_eq!(
+            flags,
+            [
+                ((0, 1), vec![String::from("shares 3 items")]),
+                ((2, 3), vec![String::from("shares no item")]),
+                ((4, 4), vec![String::from("has 1 of 2 rucksacks"), String::from("shares 3 items")]),
+            ]
+        );
+        assert!(!audit.is_clean());
+        assert!(audit.report().contains("  line 2: rucksack has an odd number of items (5)\n"));
+        assert!(audit.report().contains("  lines 5-5: group has 1 of 2 rucksacks\n"));
+    }
+
+    #[test]
+    fn finds_no_shared_items_in_a_rucksack_split_mid_character() {
+        assert_eq!(compartments_shared("aéa"), Items::NONE);
+        assert_eq!(compartments_shared("abcA"), Items::NONE);
+        assert_eq!(compartments_shared("abcb"), Items::from_items("b"));
+    }
+}
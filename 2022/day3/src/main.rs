@@ -0,0 +1,33 @@
+use std::{env, path::Path, process};
+
+use aoc2022_day3::{Audit, GROUP_SIZE};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("audit") => audit(&args[1..]),
+        _ => aoc2022_day3::SOLUTION.run(),
+    }
+}
+
+/// Prints the audit of the rucksacks at the path given, or on stdin, exiting
+/// with an error when anything was flagged.
+fn audit(args: &[String]) {
+    const USAGE: &str = "Usage: aoc2022-day3 audit [<path>] [--group-size <size>]";
+    let mut path = None;
+    let mut group_size = GROUP_SIZE;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--group-size" => group_size = args.next().and_then(|size| size.parse().ok()).unwrap_or_else(|| harness::fail(USAGE)),
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(Path::new(arg)),
+            _ => harness::fail(USAGE),
+        }
+    }
+
+    let audit = harness::parse_input(path, |input| Ok(Audit::new(&mut input.lines(), group_size)));
+    print!("{}", audit.report());
+    if !audit.is_clean() {
+        process::exit(1);
+    }
+}
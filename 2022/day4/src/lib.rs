@@ -0,0 +1,110 @@
+use std::str::Lines;
+
+use harness::{generate::Rng, AocError};
+
+pub struct Assignment {
+    pub lower_bound: u32,
+    pub upper_bound: u32,
+}
+
+impl Assignment {
+    /// Parses a range like `2-4`, a slice of the `index`th line `line`.
+    pub fn parse(index: usize, line: &str, str: &str) -> Result<Assignment, AocError> {
+        let Some((lower, upper)) = str.split_once('-') else {
+            return Err(AocError::at(index, line, str, format!("Expected a range like `2-4`, found `{}`", str)));
+        };
+        let bound = |s: &str, name: &str| {
+            s.parse::<u32>().map_err(|_| AocError::at(index, line, s, format!("Expected a {} bound, found `{}`", name, s)))
+        };
+        let lower_bound = bound(lower, "lower")?;
+        let upper_bound = bound(upper, "upper")?;
+
+        Ok(Assignment { lower_bound, upper_bound })
+    }
+
+    pub fn is_within(&self, other: &Assignment) -> bool {
+        self.lower_bound >= other.lower_bound && self.upper_bound <= other.upper_bound
+    }
+
+    pub fn overlaps(&self, other: &Assignment) -> bool {
+        if self.lower_bound > other.upper_bound || self.upper_bound < other.lower_bound {
+            return false;
+        }
+
+        true
+    }
+}
+
+pub fn parse_pair(index: usize, line: &str) -> Result<(Assignment, Assignment), AocError> {
+    let Some((left, right)) = line.split_once(',') else {
+        return Err(AocError::line(index, line, "Expected two ranges separated by a comma, like `2-4,6-8`"));
+    };
+    Ok((Assignment::parse(index, line, left)?, Assignment::parse(index, line, right)?))
+}
+
+pub fn part1(lines: &mut Lines) -> Result<u32, AocError> {
+    let mut total_contained = 0;
+    for (index, line) in lines.enumerate() {
+        let (left_assignment, right_assignment) = parse_pair(index, line)?;
+
+        if left_assignment.is_within(&right_assignment) || right_assignment.is_within(&left_assignment) {
+            total_contained += 1;
+        }
+    }
+
+    Ok(total_contained)
+}
+
+pub fn part2(lines: &mut Lines) -> Result<u32, AocError> {
+    let mut total_contained = 0;
+    for (index, line) in lines.enumerate() {
+        let (left_assignment, right_assignment) = parse_pair(index, line)?;
+
+        if left_assignment.overlaps(&right_assignment) {
+            total_contained += 1;
+        }
+    }
+
+    Ok(total_contained)
+}
+
+/// `size` pairs of section assignments between 1 and 99.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    fn assignment(rng: &mut Rng) -> String {
+        let lower_bound = rng.range(1..=99);
+        format!("{}-{}", lower_bound, rng.range(lower_bound..=99))
+    }
+    (0..size).map(|_| format!("{},{}\n", assignment(rng), assignment(rng))).collect()
+}
+
+harness::solution! {
+    year: 2022,
+    day: 4,
+    title: "Camp Cleanup",
+    tags: ["parsing", "ranges"],
+    algorithms: ["interval overlap"],
+    difficulty: 1,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8" => { part1: 2, part2: 4 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_a_bad_upper_bound() {
+        let error = part1(&mut "2-4,6-8\n2-4,6-x".lines()).unwrap_err();
+        assert_eq!(error.message, "Expected a upper bound, found `x`");
+        assert_eq!((error.line, error.column, error.len), (2, 7, 1));
+    }
+}
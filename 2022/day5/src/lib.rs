@@ -0,0 +1,294 @@
+use std::{fmt, io, str::Lines};
+
+use harness::{generate::Rng, repl::{self, Repl}, AocError};
+
+#[derive(Debug)]
+pub struct Crate {
+    pub letter: char
+}
+
+#[derive(Debug)]
+pub struct CrateStack {
+    pub crates: Vec<Crate>
+}
+
+impl CrateStack {
+    pub fn take(&mut self, number: usize, is_crate_mover_9001: bool) -> Vec<Crate> {
+        let mut x = self.crates.split_off(self.crates.len() - number);
+        if !is_crate_mover_9001 {
+            x.reverse();
+        }
+        x
+    }
+
+    pub fn add(&mut self, crates: &mut Vec<Crate>) {
+        self.crates.append(crates);
+    }
+}
+
+#[derive(Debug)]
+pub struct Move {
+    pub quantity: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// The crate stacks and the rearrangement procedure still to be carried out.
+#[derive(Debug)]
+pub struct Dock {
+    pub crate_stacks: Vec<CrateStack>,
+    pub moves: Vec<Move>,
+    pub next_move: usize,
+    pub is_crate_mover_9001: bool,
+}
+
+impl Dock {
+    pub fn parse(lines: &mut Lines, is_crate_mover_9001: bool) -> Result<Dock, AocError> {
+        let mut mode = 0;
+        let mut crate_stacks: Vec<CrateStack> = Vec::new();
+        let mut moves: Vec<Move> = Vec::new();
+        // Stack heights as the moves are read, to catch moves of missing crates.
+        let mut heights: Vec<usize> = Vec::new();
+        for (line_index, line) in lines.enumerate() {
+            if line.contains(char::is_numeric) {
+                if mode == 0 {
+                    heights = crate_stacks.iter().map(|cs| cs.crates.len()).collect();
+                }
+                mode = 1;
+            }
+
+            if mode == 0 {
+                let mut chars = line.char_indices();
+                let mut index = 0;
+                while let Some((_, first)) = chars.next() {
+
+                    let cs = match crate_stacks.get_mut(index) {
+                        Some(c) => c,
+                        None => {
+                            let s = CrateStack { crates: Vec::new() };
+                            crate_stacks.insert(index, s);
+                            crate_stacks.get_mut(index).unwrap()
+                        }
+                    };
+                    
+                    if first == '[' {
+                        let crate_letter = match chars.next() {
+                            Some((_, c)) if c.is_ascii_uppercase() => c,
+                            Some((i, c)) => return Err(AocError::at(line_index, line, &line[i..i + c.len_utf8()], "Expected a crate letter")),
+                            None => return Err(AocError::at(line_index, line, &line[line.len()..], "Expected a crate letter")),
+                        };
+                        cs.crates.insert(0, Crate { letter: crate_letter });
+                    } else {
+                        chars.next();
+                    }
+                    chars.next();
+                    chars.next();
+
+                    index += 1;
+                }
+            } else if mode == 1
+                && line.starts_with('m') {
+                    let splits: Vec<&str> = line.split(' ').collect();
+                    let ["move", raw_quantity, "from", raw_from, "to", raw_to] = splits[..] else {
+                        return Err(AocError::line(line_index, line, "Expected a move like `move 1 from 2 to 1`"));
+                    };
+                    let number = |s: &str| {
+                        s.parse::<usize>().map_err(|_| AocError::at(line_index, line, s, format!("Expected a number, found `{}`", s)))
+                    };
+                    let stack = |s: &str| match number(s)? {
+                        n if (1..=heights.len()).contains(&n) => Ok(n - 1),
+                        _ => Err(AocError::at(line_index, line, s, format!("Missing crate stack, there are {}", heights.len()))),
+                    };
+                    let quantity = number(raw_quantity)?;
+                    let from = stack(raw_from)?;
+                    let to = stack(raw_to)?;
+                    if quantity > heights[from] {
+                        let message = format!("Stack {} only has {} crates at this point", from + 1, heights[from]);
+                        return Err(AocError::at(line_index, line, raw_quantity, message));
+                    }
+                    heights[from] -= quantity;
+                    heights[to] += quantity;
+                    moves.push(Move { quantity, from, to });
+                }
+        }
+
+        Ok(Dock { crate_stacks, moves, next_move: 0, is_crate_mover_9001 })
+    }
+
+    /// Carries out the next move, returning false once there are none left.
+    pub fn step(&mut self) -> bool {
+        let Some(m) = self.moves.get(self.next_move) else {
+            return false;
+        };
+        self.next_move += 1;
+
+        // Moves were checked against the stacks when parsing.
+        let mut crane_crates = self.crate_stacks[m.from].take(m.quantity, self.is_crate_mover_9001);
+        self.crate_stacks[m.to].add(&mut crane_crates);
+        true
+    }
+
+    pub fn top_crates(&self) -> String {
+        let mut result = String::new();
+        for crate_stack in self.crate_stacks.iter() {
+            if let Some(cr8t) = crate_stack.crates.last() {
+                result.push(cr8t.letter);
+            }
+        }
+        result
+    }
+}
+
+/// Draws the stacks the way the puzzle input does.
+impl fmt::Display for Dock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let height = self.crate_stacks.iter().map(|cs| cs.crates.len()).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let row: Vec<String> = self
+                .crate_stacks
+                .iter()
+                .map(|cs| match cs.crates.get(level) {
+                    Some(c) => format!("[{}]", c.letter),
+                    None => String::from("   "),
+                })
+                .collect();
+            writeln!(f, "{}", row.join(" ").trim_end())?;
+        }
+        let labels: Vec<String> = (1..=self.crate_stacks.len()).map(|i| format!(" {} ", i)).collect();
+        write!(f, "{}", labels.join(" ").trim_end())
+    }
+}
+
+pub fn part1(lines: &mut Lines) -> Result<String, AocError> {
+    let mut dock = Dock::parse(lines, false)?;
+    while dock.step() {}
+    Ok(dock.top_crates())
+}
+
+pub fn part2(lines: &mut Lines) -> Result<String, AocError> {
+    let mut dock = Dock::parse(lines, true)?;
+    while dock.step() {}
+    Ok(dock.top_crates())
+}
+
+pub fn repl(lines: &mut Lines) -> io::Result<()> {
+    new_repl(lines)?.run()
+}
+
+/// The REPL `repl` runs, with the day's commands registered.
+pub fn new_repl(lines: &mut Lines) -> Result<Repl<Dock>, AocError> {
+    let repl = Repl::new("day5", Dock::parse(lines, false)?)
+        .command("step", "[n] carry out the next n moves", |dock, args| {
+            for _ in 0..repl::count(args)? {
+                if !dock.step() {
+                    return Err(String::from("No moves left"));
+                }
+            }
+            Ok(dock.to_string())
+        })
+        .command("next", "show the next move", |dock, _| match dock.moves.get(dock.next_move) {
+            Some(m) => Ok(format!("move {} from {} to {}", m.quantity, m.from + 1, m.to + 1)),
+            None => Err(String::from("No moves left")),
+        })
+        .command("render", "draw the stacks", |dock, _| Ok(dock.to_string()))
+        .command("top", "show the crates on top of each stack", |dock, _| Ok(dock.top_crates()))
+        .command("9001", "switch between the CrateMover 9000 and 9001", |dock, _| {
+            dock.is_crate_mover_9001 = !dock.is_crate_mover_9001;
+            Ok(format!("CrateMover {}", if dock.is_crate_mover_9001 { 9001 } else { 9000 }))
+        });
+    Ok(repl)
+}
+
+/// Nine stacks and `size` moves that never take more crates than a stack holds.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut heights: Vec<usize> = (0..9).map(|_| rng.range(1..=8) as usize).collect();
+    let tallest = *heights.iter().max().unwrap();
+    let mut out = String::new();
+    for level in (0..tallest).rev() {
+        let row: Vec<String> = heights
+            .iter()
+            .map(|&height| match level < height {
+                true => format!("[{}]", rng.word("ABCDEFGHIJKLMNOPQRSTUVWXYZ", 1)),
+                false => String::from("   "),
+            })
+            .collect();
+        out.push_str(&row.join(" "));
+        out.push('\n');
+    }
+    let labels: Vec<String> = (1..=heights.len()).map(|i| format!(" {} ", i)).collect();
+    out.push_str(&labels.join(" "));
+    out.push_str("\n\n");
+
+    for _ in 0..size {
+        let from = loop {
+            let i = rng.below(heights.len());
+            if heights[i] > 0 {
+                break i;
+            }
+        };
+        let to = loop {
+            let i = rng.below(heights.len());
+            if i != from {
+                break i;
+            }
+        };
+        let quantity = rng.range(1..=heights[from] as i64) as usize;
+        heights[from] -= quantity;
+        heights[to] += quantity;
+        out.push_str(&format!("move {} from {} to {}\n", quantity, from + 1, to + 1));
+    }
+    out
+}
+
+harness::solution! {
+    year: 2022,
+    day: 5,
+    title: "Supply Stacks",
+    tags: ["parsing", "simulation"],
+    algorithms: ["stacks"],
+    difficulty: 2,
+    repl: repl,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2" => { part1: "CMZ", part2: "MCD" },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_a_move_from_a_missing_stack() {
+        let error = part1(&mut "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 3 to 1".lines()).unwrap_err();
+        assert_eq!(error.message, "Missing crate stack, there are 2");
+        assert_eq!((error.line, error.column, error.len), (5, 13, 1));
+    }
+
+    #[test]
+    fn moves_crates_in_the_repl() {
+        let example = harness::testing::example_input(crate::example_tests::example::INPUT);
+        let mut repl = new_repl(&mut example.lines()).unwrap();
+        let mut execute = |line: &str| repl.execute(line).unwrap();
+        assert_eq!(execute("next"), Ok(String::from("move 1 from 2 to 1")));
+        assert_eq!(execute("top"), Ok(String::from("NDP")));
+        assert!(execute("step").is_ok());
+        assert_eq!(execute("top"), Ok(String::from("DCP")));
+        assert!(execute("step 3").is_ok());
+        assert_eq!(execute("top"), Ok(String::from("CMZ")));
+        assert_eq!(execute("next"), Err(String::from("No moves left")));
+        assert_eq!(execute("step"), Err(String::from("No moves left")));
+        assert_eq!(execute("9001"), Ok(String::from("CrateMover 9001")));
+        assert_eq!(execute("step x"), Err(String::from("Invalid count: x")));
+    }
+}
@@ -0,0 +1,103 @@
+use std::str::Lines;
+
+use harness::{generate::Rng, AocError};
+
+pub struct DataStreamBuffer {
+    pub data: String
+}
+
+impl DataStreamBuffer {
+    pub fn parse(index: usize, line: &str) -> Result<DataStreamBuffer, AocError> {
+        if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            return Err(AocError::at(index, line, &line[i..i + c.len_utf8()], format!("Expected a lowercase letter, found `{}`", c)));
+        }
+        Ok(DataStreamBuffer { data: String::from(line) })
+    }
+
+    pub fn find_start_of_packet_marker_index(&self, distinct_chars: usize) -> usize {
+        let mut prev_chars: Vec<char> = vec![];
+        let mut index = 0;
+        for ch in self.data.chars() {
+            index += 1;
+            if prev_chars.contains(&ch) {
+                // remove until ch
+                let mut new_chars: Vec<char> = vec![];
+                for nch in prev_chars.iter().skip_while(|x| *x != &ch).skip(1) {
+                    new_chars.push(*nch);
+                }
+                prev_chars = new_chars;
+            }
+            prev_chars.push(ch);
+
+            if prev_chars.len() == distinct_chars {
+                break;
+            }
+        }
+
+        index
+    }
+}
+
+pub fn part1(lines: &mut Lines) -> Result<String, AocError> {
+    let mut results: Vec<String> = Vec::new();
+    for (index, line) in lines.enumerate() {
+        results.push(DataStreamBuffer::parse(index, line)?.find_start_of_packet_marker_index(4).to_string());
+    }
+    
+    Ok(results.join("; "))
+}
+
+pub fn part2(lines: &mut Lines) -> Result<String, AocError> {
+    let mut results: Vec<String> = Vec::new();
+    for (index, line) in lines.enumerate() {
+        results.push(DataStreamBuffer::parse(index, line)?.find_start_of_packet_marker_index(14).to_string());
+    }
+    
+    Ok(results.join("; "))
+}
+
+/// One datastream of `size` characters. Everything before the
+/// start-of-message marker uses only 13 letters, so the marker sits near the
+/// end and the whole stream has to be scanned.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut letters: Vec<char> = ('a'..='z').collect();
+    rng.shuffle(&mut letters);
+    let marker_index = size.saturating_sub(14 + size / 20);
+    let mut data: String = (0..marker_index).map(|_| *rng.pick(&letters[..13])).collect();
+    rng.shuffle(&mut letters);
+    data.extend(&letters[..14]);
+    while data.len() < size {
+        data.push(*rng.pick(&letters));
+    }
+    data + "\n"
+}
+
+harness::solution! {
+    year: 2022,
+    day: 6,
+    title: "Tuning Trouble",
+    tags: ["strings"],
+    algorithms: ["sliding window"],
+    difficulty: 1,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+bvwbjplbgvbhsrlpgdmjqwftvncz
+nppdvjthqldpwncqszvftbrmjlhg
+nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg
+zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw" => { part1: "5; 6; 10; 11", part2: "23; 23; 29; 26" },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_a_character_that_is_not_a_letter() {
+        let error = part1(&mut "abc1def".lines()).unwrap_err();
+        assert_eq!(error.message, "Expected a lowercase letter, found `1`");
+        assert_eq!((error.line, error.column, error.len), (1, 4, 1));
+    }
+}
@@ -0,0 +1,389 @@
+use std::cell::RefCell;
+use std::fmt::{self, Debug};
+use std::io;
+use std::ops::Add;
+use std::rc::{Rc, Weak};
+use std::str::Lines;
+
+use harness::{generate::Rng, repl::{self, Repl}, AocError};
+
+#[derive(Clone, Copy, Debug)]
+pub enum ItemType {
+    File,
+    Directory,
+}
+
+#[derive(Debug)]
+pub struct Item {
+    pub name: String,
+    pub item_type: ItemType,
+    pub size: RefCell<u32>,
+    pub parent: Option<Weak<Item>>,
+    pub children: RefCell<Vec<Rc<Item>>>,
+}
+
+impl Item {
+    pub fn new(name: &str, item_type: ItemType, size: RefCell<u32>, parent: Option<Weak<Item>>) -> Item {
+        Item { name: name.to_string(), item_type, size, parent, children: RefCell::new(vec![]) }
+    }
+
+    pub fn update_size(&self, size: u32) {
+        let current_size = *self.size.borrow();
+        *self.size.borrow_mut() = current_size.add(size);
+        if let Some(p) = &self.parent {
+            p.upgrade().unwrap().update_size(size);
+        }
+            
+    }
+
+    pub fn add_child(&self, item: Rc<Item>) {
+        self.children.borrow_mut().push(item);
+    }
+
+    pub fn get_child(&self, name: String) -> Option<Rc<Item>> {
+        for child in self.children.borrow().iter() {
+            if child.name == name {
+                return Some(child.to_owned());
+            }
+        }
+        None
+    }
+
+    pub fn get_parent(&self) -> &Weak<Item> {
+        match &self.parent {
+            Some(p) => p,
+            None => panic!("No parent found!"),
+        }
+    }
+}
+
+/// The directory tree rebuilt from the terminal output seen so far.
+#[derive(Debug)]
+pub struct FileSystem {
+    pub root_item: Rc<Item>,
+    pub current_item: Rc<Item>,
+    pub directories: Vec<Weak<Item>>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileSystem {
+    pub fn new() -> FileSystem {
+        let root_item = Rc::new(Item::new("/", ItemType::Directory, RefCell::new(0), None));
+        FileSystem { directories: vec![Rc::downgrade(&root_item)], current_item: Rc::clone(&root_item), root_item }
+    }
+
+    pub fn parse(lines: &mut Lines) -> Result<FileSystem, AocError> {
+        let mut file_system = FileSystem::new();
+        for (index, line) in lines.enumerate() {
+            file_system.execute(index, line)?;
+        }
+        Ok(file_system)
+    }
+
+    /// Applies the `index`th line of terminal output, either a command or a
+    /// listing.
+    pub fn execute(&mut self, index: usize, line: &str) -> Result<(), AocError> {
+        let splits: Vec<&str> = line.split(' ').collect();
+        if line.starts_with('$') {
+            // parse command
+            match splits[..] {
+                ["$", "cd", name] => {
+                    if name == "/" {
+                        self.current_item = Rc::clone(&self.root_item);
+                    } else if name == ".." {
+                        let Some(weak_parent) = &self.current_item.parent else {
+                            return Err(AocError::at(index, line, name, "Already at the root directory"));
+                        };
+                        let parent = weak_parent.upgrade().unwrap();
+                        self.current_item = parent;
+                    } else {
+                        let cd = match self.current_item.get_child(name.to_string()) {
+                            Some(cd) => cd,
+                            None => return Err(AocError::at(index, line, name, format!("No directory `{}` has been listed here", name))),
+                        };
+                        self.current_item = cd;
+                    }
+                }
+                ["$", "ls"] => {}
+                _ => return Err(AocError::line(index, line, "Expected `$ cd <dir>` or `$ ls`")),
+            }
+        } else {
+            let [description, name] = splits[..] else {
+                return Err(AocError::line(index, line, "Expected `dir <name>` or `<size> <name>`"));
+            };
+
+            let item_type = match description {
+                "dir" => ItemType::Directory,
+                &_ => ItemType::File,
+            };
+
+            let size = match item_type {
+                ItemType::Directory => 0,
+                ItemType::File => description
+                    .parse::<u32>()
+                    .map_err(|_| AocError::at(index, line, description, format!("Expected a file size, found `{}`", description)))?,
+            };
+
+            // parse files
+            let new_item = Item::new(name, item_type, RefCell::new(size), Some(Rc::downgrade(&self.current_item)));
+            let rc_new_item = Rc::new(new_item);
+            
+            match item_type {
+                ItemType::File => self.current_item.update_size(size),
+                ItemType::Directory => self.directories.push(Rc::downgrade(&rc_new_item)),
+            }
+
+            self.current_item.add_child(rc_new_item);
+        }
+        Ok(())
+    }
+
+    pub fn directory_sizes(&self) -> Vec<u32> {
+        self.directories.iter().map(|weak_dir| weak_dir.upgrade().unwrap().size.borrow().to_owned()).collect()
+    }
+
+    pub fn path(&self) -> String {
+        let mut names = vec![];
+        let mut item = Some(Rc::clone(&self.current_item));
+        while let Some(i) = item {
+            names.push(i.name.clone());
+            item = i.parent.as_ref().and_then(|p| p.upgrade());
+        }
+        names.pop();
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+}
+
+fn write_tree(f: &mut fmt::Formatter, item: &Item, depth: usize) -> fmt::Result {
+    let kind = match item.item_type {
+        ItemType::File => "file",
+        ItemType::Directory => "dir",
+    };
+    write!(f, "{}- {} ({}, size={})", "  ".repeat(depth), item.name, kind, item.size.borrow())?;
+    for child in item.children.borrow().iter() {
+        writeln!(f)?;
+        write_tree(f, child, depth + 1)?;
+    }
+    Ok(())
+}
+
+/// Draws the tree like the puzzle description does.
+impl fmt::Display for FileSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_tree(f, &self.root_item, 0)
+    }
+}
+
+pub fn part1(lines: &mut Lines) -> Result<u32, AocError> {
+    let file_system = FileSystem::parse(lines)?;
+    Ok(file_system.directory_sizes().into_iter().filter(|&dir_size| dir_size < 100000).sum())
+}
+
+pub fn part2(lines: &mut Lines) -> Result<u32, AocError> {
+    let line_count = lines.clone().count();
+    let file_system = FileSystem::parse(lines)?;
+
+    let total_disk_space: u32 = 70000000;
+    let required_free_disk_space: u32 = 30000000;
+    let used_disk_space = file_system.root_item.size.borrow().to_owned();
+    let Some(free_disk_space) = total_disk_space.checked_sub(used_disk_space) else {
+        return Err(AocError::end_of_input(line_count, format!("Files take up {}, more than the disk's {}", used_disk_space, total_disk_space)));
+    };
+    let Some(disk_space_to_free) = required_free_disk_space.checked_sub(free_disk_space) else {
+        return Err(AocError::end_of_input(line_count, format!("{} is already free, no directory needs deleting", free_disk_space)));
+    };
+
+    let mut potential_dir = u32::MAX;
+    for dir_size in file_system.directory_sizes() {
+        if dir_size > disk_space_to_free && dir_size < potential_dir {
+            potential_dir = dir_size;
+        }
+    }
+
+    Ok(potential_dir)
+}
+
+/// Terminal output replayed a line at a time.
+#[derive(Debug)]
+pub struct Session {
+    pub file_system: FileSystem,
+    pub output: Vec<String>,
+    pub next_line: usize,
+}
+
+impl Session {
+    pub fn step(&mut self) -> Result<Option<&str>, AocError> {
+        let Some(line) = self.output.get(self.next_line) else {
+            return Ok(None);
+        };
+        self.file_system.execute(self.next_line, line)?;
+        self.next_line += 1;
+        Ok(Some(line))
+    }
+}
+
+pub fn repl(lines: &mut Lines) -> io::Result<()> {
+    new_repl(lines)?.run()
+}
+
+/// The REPL `repl` runs, with the day's commands registered.
+pub fn new_repl(lines: &mut Lines) -> Result<Repl<Session>, AocError> {
+    let session = Session { file_system: FileSystem::new(), output: lines.map(String::from).collect(), next_line: 0 };
+    let repl = Repl::new("day7", session)
+        .command("step", "[n] replay the next n lines of output", |session, args| {
+            let mut replayed = vec![];
+            for _ in 0..repl::count(args)? {
+                match session.step().map_err(|e| e.to_string())? {
+                    Some(line) => replayed.push(line.to_string()),
+                    None => return Err(String::from("No output left")),
+                }
+            }
+            Ok(replayed.join("\n"))
+        })
+        .command("run", "replay the rest of the output", |session, _| {
+            while session.step().map_err(|e| e.to_string())?.is_some() {}
+            Ok(session.file_system.to_string())
+        })
+        .command("render", "draw the directory tree", |session, _| Ok(session.file_system.to_string()))
+        .command("pwd", "show the current directory", |session, _| Ok(session.file_system.path()))
+        .command("sizes", "list directory sizes", |session, _| {
+            let sizes: Vec<String> = session
+                .file_system
+                .directories
+                .iter()
+                .filter_map(|d| d.upgrade())
+                .map(|d| format!("{}\t{}", d.name, d.size.borrow()))
+                .collect();
+            Ok(sizes.join("\n"))
+        });
+    Ok(repl)
+}
+
+/// A terminal session exploring `size` files and directories, the first
+/// always a file. Like real inputs, the files take up between 40000000 and
+/// 70000000 so there is a directory worth deleting.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // Index 0 is the root directory.
+    let mut names = vec![String::from("/")];
+    let mut is_directory = vec![true];
+    let mut children: Vec<Vec<usize>> = vec![vec![]];
+    let mut sizes = vec![0];
+    for index in 1..=size.max(1) {
+        let directories: Vec<usize> = (0..index).filter(|&i| is_directory[i]).collect();
+        let parent = *rng.pick(&directories);
+        let name = loop {
+            let len = rng.range(1..=8) as usize;
+            let name = rng.word("abcdefghijklmnopqrstuvwxyz", len);
+            if !children[parent].iter().any(|&c| names[c] == name) {
+                break name;
+            }
+        };
+        let directory = index > 1 && rng.chance(0.3);
+        names.push(if directory || rng.chance(0.5) { name } else { format!("{}.{}", name, rng.word("abcdefghijklmnopqrstuvwxyz", 3)) });
+        is_directory.push(directory);
+        children.push(vec![]);
+        sizes.push(if directory { 0 } else { rng.range(1..=300000) as u64 });
+        children[parent].push(index);
+    }
+
+    let total: u64 = sizes.iter().sum();
+    let target = rng.range(42000000..=68000000) as u64;
+    for file_size in sizes.iter_mut().filter(|s| **s > 0) {
+        *file_size = (*file_size * target / total).max(1);
+    }
+
+    fn list(out: &mut String, dir: usize, names: &[String], children: &[Vec<usize>], sizes: &[u64], is_directory: &[bool]) {
+        out.push_str("$ ls\n");
+        for &child in &children[dir] {
+            match is_directory[child] {
+                true => out.push_str(&format!("dir {}\n", names[child])),
+                false => out.push_str(&format!("{} {}\n", sizes[child], names[child])),
+            }
+        }
+        for &child in children[dir].iter().filter(|&&c| is_directory[c]) {
+            out.push_str(&format!("$ cd {}\n", names[child]));
+            list(out, child, names, children, sizes, is_directory);
+            out.push_str("$ cd ..\n");
+        }
+    }
+    let mut out = String::from("$ cd /\n");
+    list(&mut out, 0, &names, &children, &sizes, &is_directory);
+    out
+}
+
+harness::solution! {
+    year: 2022,
+    day: 7,
+    title: "No Space Left On Device",
+    tags: ["parsing", "tree"],
+    algorithms: ["tree traversal"],
+    difficulty: 2,
+    repl: repl,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k" => { part1: 95437, part2: 24933642 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_a_cd_into_an_unlisted_directory() {
+        let error = part1(&mut "$ cd /\n$ ls\ndir a\n$ cd b".lines()).unwrap_err();
+        assert_eq!(error.message, "No directory `b` has been listed here");
+        assert_eq!((error.line, error.column, error.len), (4, 6, 1));
+    }
+
+    #[test]
+    fn replays_the_output_in_the_repl() {
+        let example = harness::testing::example_input(crate::example_tests::example::INPUT);
+        let mut repl = new_repl(&mut example.lines()).unwrap();
+        let mut execute = |line: &str| repl.execute(line).unwrap();
+        assert_eq!(execute("step 2"), Ok(String::from("$ cd /\n$ ls")));
+        assert_eq!(execute("step 5"), Ok(String::from("dir a\n14848514 b.txt\n8504156 c.dat\ndir d\n$ cd a")));
+        assert_eq!(execute("pwd"), Ok(String::from("/a")));
+        assert!(execute("run").unwrap().starts_with("- / (dir, size=48381165)\n  - a (dir, size=94853)\n"));
+        assert_eq!(execute("pwd"), Ok(String::from("/d")));
+        assert_eq!(execute("sizes"), Ok(String::from("/\t48381165\na\t94853\nd\t24933642\ne\t584")));
+        assert_eq!(execute("step"), Err(String::from("No output left")));
+    }
+
+    #[test]
+    fn reports_a_disk_with_enough_free_space() {
+        let error = part2(&mut "$ cd /\n$ ls\n100 a".lines()).unwrap_err();
+        assert_eq!(error.message, "69999900 is already free, no directory needs deleting");
+        assert_eq!(error.line, 4);
+    }
+}
@@ -1,10 +1,11 @@
 use std::hash::Hash;
 use std::str::Lines;
 use std::collections::{HashMap, HashSet};
-use harness;
+
+use harness::{generate::Rng, AocError};
 
 #[derive(Clone, Copy, Debug)]
-enum Direction {
+pub enum Direction {
     Top,
     Bottom,
     Left,
@@ -12,7 +13,7 @@ enum Direction {
 }
 
 #[derive(PartialEq, Eq, Hash, Debug)]
-enum Location {
+pub enum Location {
     FromTop(usize),
     FromBottom(usize),
     FromLeft(usize),
@@ -20,7 +21,7 @@ enum Location {
 }
 
 impl Location {
-    fn from_direction(dir: &Direction, dim: usize) -> Location {
+    pub fn from_direction(dir: &Direction, dim: usize) -> Location {
         match dir {
             Direction::Top => Location::FromTop(dim),
             Direction::Bottom => Location::FromBottom(dim),
@@ -30,24 +31,24 @@ impl Location {
     }
 }
 
-struct TreePatch {
-    tree_heights: Vec<usize>,
-    dimension: usize,
-    scenic_score_memo: HashMap<Location, usize>,
+pub struct TreePatch {
+    pub tree_heights: Vec<usize>,
+    pub dimension: usize,
+    pub scenic_score_memo: HashMap<Location, usize>,
 }
 
 impl TreePatch {
-    fn new(tree_heights: Vec<usize>) -> TreePatch {
+    pub fn new(tree_heights: Vec<usize>) -> TreePatch {
         let dimension = (tree_heights.len() as f64).sqrt() as usize;
 
         TreePatch {
-            tree_heights: tree_heights,
-            dimension: dimension,
+            tree_heights,
+            dimension,
             scenic_score_memo: HashMap::new(),
         }
     }
 
-    fn pass(&mut self, dir: Direction) -> Vec<usize> {
+    pub fn pass(&mut self, dir: Direction) -> Vec<usize> {
         let mut visible_indices: Vec<usize> = Vec::new();
         let mut max_heights: HashMap<Location, usize> = HashMap::new();
 
@@ -79,7 +80,7 @@ impl TreePatch {
                 let mut v: Vec<usize> = Vec::new();
                 for i in 0..inner_dim+1 {
                     v.append(
-                        &mut ((start_index + i)..((start_index + i + (self.dimension * inner_dim)))).step_by(self.dimension)
+                        &mut ((start_index + i)..(start_index + i + (self.dimension * inner_dim))).step_by(self.dimension)
                         .collect::<Vec<usize>>()
                     );
                 }
@@ -89,7 +90,7 @@ impl TreePatch {
                 let mut v: Vec<usize> = Vec::new();
                 for i in 0..inner_dim+1 {
                     v.append(
-                        &mut ((start_index + (inner_dim + 1 - i))..(((start_index + (inner_dim + 1 - i)) + (self.dimension * inner_dim)))).step_by(self.dimension)
+                        &mut ((start_index + (inner_dim + 1 - i))..((start_index + (inner_dim + 1 - i)) + (self.dimension * inner_dim))).step_by(self.dimension)
                             .collect::<Vec<usize>>()
                     );
                 }
@@ -123,7 +124,7 @@ impl TreePatch {
         visible_indices
     }
 
-    fn get_visible_trees(&mut self) -> HashSet<usize> {
+    pub fn get_visible_trees(&mut self) -> HashSet<usize> {
         let mut visible_tree_indices: HashSet<usize> = HashSet::new();
         self.pass(Direction::Top).iter().for_each(|i| {
             visible_tree_indices.insert(*i);
@@ -141,19 +142,19 @@ impl TreePatch {
         visible_tree_indices
     }
 
-    fn get_scenic_score(&mut self, index: usize) -> usize {
+    pub fn get_scenic_score(&mut self, index: usize) -> usize {
         self.get_scenic_score_from_dir(index, Direction::Top) *
         self.get_scenic_score_from_dir(index, Direction::Bottom) *
         self.get_scenic_score_from_dir(index, Direction::Left) * 
         self.get_scenic_score_from_dir(index, Direction::Right)
     }
 
-    fn get_index_from_dir(&self, index: usize, displacement: usize, dir: Direction) -> Option<usize> {
+    pub fn get_index_from_dir(&self, index: usize, displacement: usize, dir: Direction) -> Option<usize> {
         match dir {
             Direction::Top => index.checked_sub(self.dimension * displacement),
             Direction::Bottom => Some(index + (self.dimension * displacement)),
             Direction::Left => {
-                if index % self.dimension == 0 {
+                if index.is_multiple_of(self.dimension) {
                     None
                 } else {
                     index.checked_sub(displacement)
@@ -169,7 +170,7 @@ impl TreePatch {
         }
     }
 
-    fn get_scenic_score_from_dir(&mut self, index: usize, dir: Direction) -> usize {
+    pub fn get_scenic_score_from_dir(&mut self, index: usize, dir: Direction) -> usize {
         if let Some(v) = self.scenic_score_memo.get(&Location::from_direction(&dir, index)) {
             return *v;
         };
@@ -222,7 +223,7 @@ impl TreePatch {
         scenic_score
     }
 
-    fn get_highest_scenic_score(&mut self) -> (usize, usize) {
+    pub fn get_highest_scenic_score(&mut self) -> (usize, usize) {
         let mut highest_scenic_score = 0;
         let mut index = 0;
         for i in 0..self.tree_heights.len() {
@@ -236,13 +237,31 @@ impl TreePatch {
     }
 }
 
-fn part1(lines: &mut Lines) -> usize {
-    let tree_heights =
-        lines.map(|l| l.to_string()).collect::<Vec<String>>()
-        .join("")
-        .chars()
-        .map(|c| c.to_digit(10).unwrap() as usize)
-        .collect::<Vec<usize>>();
+/// Reads the grid row by row. The patch has to be square, and at least 3 wide
+/// so it has an interior.
+pub fn parse_tree_heights(lines: &mut Lines) -> Result<Vec<usize>, AocError> {
+    let rows: Vec<&str> = lines.collect();
+    if rows.is_empty() {
+        return Err(AocError::end_of_input(0, "Expected a patch of trees"));
+    }
+    let mut tree_heights = Vec::new();
+    for (index, line) in rows.iter().enumerate() {
+        if line.chars().count() != rows.len() || rows.len() < 3 {
+            let message = format!("Expected a square patch at least 3 wide, found {} rows of {}", rows.len(), line.chars().count());
+            return Err(AocError::line(index, line, message));
+        }
+        for (i, c) in line.char_indices() {
+            match c.to_digit(10) {
+                Some(height) => tree_heights.push(height as usize),
+                None => return Err(AocError::at(index, line, &line[i..i + c.len_utf8()], format!("Expected a tree height, found `{}`", c))),
+            }
+        }
+    }
+    Ok(tree_heights)
+}
+
+pub fn part1(lines: &mut Lines) -> Result<usize, AocError> {
+    let tree_heights = parse_tree_heights(lines)?;
 
     let mut tree_patch = TreePatch::new(tree_heights.clone());
     let visible_tree_indices = tree_patch.get_visible_trees();
@@ -259,16 +278,11 @@ fn part1(lines: &mut Lines) -> usize {
     //     }
     // }
 
-    visible_tree_indices.len() + 4
+    Ok(visible_tree_indices.len() + 4)
 }
 
-fn part2(lines: &mut Lines) -> usize {
-    let tree_heights =
-        lines.map(|l| l.to_string()).collect::<Vec<String>>()
-        .join("")
-        .chars()
-        .map(|c| c.to_digit(10).unwrap() as usize)
-        .collect::<Vec<usize>>();
+pub fn part2(lines: &mut Lines) -> Result<usize, AocError> {
+    let tree_heights = parse_tree_heights(lines)?;
 
     let mut tree_patch = TreePatch::new(tree_heights.clone());
     let (_index, score) = tree_patch.get_highest_scenic_score();
@@ -307,12 +321,42 @@ fn part2(lines: &mut Lines) -> usize {
     //     }
     // }
 
-    score
+    Ok(score)
 }
 
-fn main() {
-    harness::time_function("./example.txt", &part1);
-    harness::time_function("./data.txt", &part1);
-    harness::time_function("./example.txt", &part2);
-    harness::time_function("./data.txt", &part2);
+/// A `size` by `size` patch of tree heights.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    (0..size).map(|_| rng.word("0123456789", size) + "\n").collect()
+}
+
+harness::solution! {
+    year: 2022,
+    day: 8,
+    title: "Treetop Tree House",
+    tags: ["grid"],
+    algorithms: ["line of sight scan"],
+    difficulty: 2,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+30373
+25512
+65332
+33549
+35390" => { part1: 21, part2: 8 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_a_tree_that_is_not_a_digit() {
+        let error = part1(&mut "123\n4x6\n789".lines()).unwrap_err();
+        assert_eq!(error.message, "Expected a tree height, found `x`");
+        assert_eq!((error.line, error.column, error.len), (2, 2, 1));
+    }
 }
@@ -0,0 +1,256 @@
+use std::{str::Lines, collections::HashSet};
+use harness::{self, generate::Rng, AocError, FrameRecorder};
+
+#[derive(Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub fn from(s: &str) -> Option<Direction> {
+        match s {
+            "R" => Some(Direction::Right),
+            "L" => Some(Direction::Left),
+            "U" => Some(Direction::Up),
+            "D" => Some(Direction::Down),
+            &_ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coordinates {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Coordinates {
+    pub fn distance_to(&self, coord: Coordinates) -> Coordinates {
+        Coordinates {
+            x: coord.x - self.x,
+            y: coord.y - self.y,
+        }
+    }
+
+    pub fn update(&mut self, coord: Coordinates) {
+        self.x += coord.x;
+        self.y += coord.y;
+    }
+
+    pub fn max_mag(&self) -> i32 {
+        let abs_x = self.x.abs();
+        let abs_y = self.y.abs();
+        if abs_x > abs_y {
+            abs_x
+        } else {
+            abs_y
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Rope {
+    pub knots: Vec<Coordinates>,
+}
+
+impl Rope {
+    pub fn new(size: usize) -> Rope {
+        let mut v = Vec::with_capacity(size);
+        for _ in 0..size {
+            v.push(Coordinates { x: 0, y: 0 });
+        }
+        Rope {
+            knots: v,
+        }
+    }
+
+    pub fn move_head(&mut self, dir: &Direction) {
+        let c = match dir {
+            Direction::Up => {
+                Coordinates { x: 0, y: 1 }
+            },
+            Direction::Down => {
+                Coordinates { x: 0, y: -1 }
+            },
+            Direction::Left => {
+                Coordinates { x: -1, y: 0 }
+            },
+            Direction::Right => {
+                Coordinates { x: 1, y: 0 }
+            },
+        };
+
+        self.knots[0].update(c);
+    }
+
+    pub fn move_knot(&mut self, index: usize) {
+        let previous_knot = self.knots[index-1];
+        let dist = self.knots[index].distance_to(previous_knot);
+
+        let update = match dist.max_mag() {
+            0 | 1 => {
+                Coordinates { x: 0, y: 0 }
+            },
+            2 => {
+                let mut x_move = 0;
+                if dist.x > 0 {
+                    x_move = 1;
+                } else if dist.x < 0 {
+                    x_move = -1;
+                }
+
+                let mut y_move = 0;
+                if dist.y > 0 {
+                    y_move = 1;
+                } else if dist.y < 0 {
+                    y_move = -1;
+                }
+
+                Coordinates { x: x_move, y: y_move }
+            },
+            _ => {
+                panic!("Tail is too far from head, something is wrong");
+            },
+        };
+
+        self.knots[index].update(update);
+    }
+
+    pub fn motion(&mut self, dir: &Direction) {
+        self.move_head(dir);
+        for (index, _coords) in self.knots.clone().iter().enumerate().skip(1) {
+            self.move_knot(index);
+        }
+    }
+
+    pub fn render(&self, visited: &HashSet<Coordinates>) -> String {
+        let start = Coordinates { x: 0, y: 0 };
+        let all = self.knots.iter().chain(visited.iter()).chain(std::iter::once(&start));
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (0, 0, 0, 0);
+        for c in all {
+            min_x = min_x.min(c.x);
+            min_y = min_y.min(c.y);
+            max_x = max_x.max(c.x);
+            max_y = max_y.max(c.y);
+        }
+
+        let mut str = String::new();
+        for y in (min_y..=max_y).rev() {
+            for x in min_x..=max_x {
+                let coord = Coordinates { x, y };
+                let c = match self.knots.iter().position(|k| *k == coord) {
+                    Some(0) => 'H',
+                    Some(1) if self.knots.len() == 2 => 'T',
+                    Some(i) => char::from_digit(i as u32, 36).unwrap_or('?'),
+                    None if coord == start => 's',
+                    None if visited.contains(&coord) => '#',
+                    None => '.',
+                };
+                str.push(c);
+            }
+            str.push('\n');
+        }
+        str
+    }
+}
+
+pub fn parse_motion(index: usize, line: &str) -> Result<(Direction, u32), AocError> {
+    let Some((raw_dir, raw_amt)) = line.split_once(' ') else {
+        return Err(AocError::line(index, line, "Expected a motion like `R 4`"));
+    };
+    let dir = Direction::from(raw_dir)
+        .ok_or_else(|| AocError::at(index, line, raw_dir, format!("Expected R, L, U or D, found `{}`", raw_dir)))?;
+    let amt = raw_amt
+        .parse::<u32>()
+        .map_err(|_| AocError::at(index, line, raw_amt, format!("Expected a number of steps, found `{}`", raw_amt)))?;
+    Ok((dir, amt))
+}
+
+pub fn simulate(lines: &mut Lines, size: usize, recorder: &mut FrameRecorder) -> Result<usize, AocError> {
+    let mut rope = Rope::new(size);
+    let mut unique_tails_coords: HashSet<Coordinates> = HashSet::new();
+    unique_tails_coords.insert(*rope.knots.last().unwrap());
+    recorder.record(|| rope.render(&unique_tails_coords));
+    for (index, line) in lines.enumerate() {
+        let (dir, amt) = parse_motion(index, line)?;
+        (0..amt).for_each(|_| {
+            rope.motion(&dir);
+            unique_tails_coords.insert(*rope.knots.last().unwrap());
+            recorder.record(|| rope.render(&unique_tails_coords));
+        });
+    }
+
+    Ok(unique_tails_coords.len())
+}
+
+pub fn simulate_part1(lines: &mut Lines, recorder: &mut FrameRecorder) -> Result<usize, AocError> {
+    simulate(lines, 2, recorder)
+}
+
+pub fn simulate_part2(lines: &mut Lines, recorder: &mut FrameRecorder) -> Result<usize, AocError> {
+    simulate(lines, 10, recorder)
+}
+
+pub fn part1(lines: &mut Lines) -> Result<usize, AocError> {
+    simulate(lines, 2, &mut FrameRecorder::new())
+}
+
+pub fn part2(lines: &mut Lines) -> Result<usize, AocError> {
+    simulate(lines, 10, &mut FrameRecorder::new())
+}
+
+/// `size` motions of up to 20 steps each.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| format!("{} {}\n", rng.pick(&["R", "L", "U", "D"]), rng.range(1..=20))).collect()
+}
+
+harness::solution! {
+    year: 2022,
+    day: 9,
+    title: "Rope Bridge",
+    tags: ["grid", "simulation"],
+    algorithms: ["knot following"],
+    difficulty: 2,
+    generate: generate,
+}
+
+harness::example_tests! {
+    example: "
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2" => { part1: 13, part2: 1 },
+    larger: "
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20" => { part2: 36 },
+}
+
+harness::snapshot_tests! {
+    example => [simulate_part1, simulate_part2],
+    larger => [simulate_part2],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_an_unknown_direction() {
+        let error = part1(&mut "R 4\nX 2".lines()).unwrap_err();
+        assert_eq!(error.message, "Expected R, L, U or D, found `X`");
+        assert_eq!((error.line, error.column, error.len), (2, 1, 1));
+    }
+}
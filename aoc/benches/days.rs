@@ -0,0 +1,85 @@
+use std::{env, fs, path::PathBuf, time::Duration};
+
+use aoc::registry::SOLUTIONS;
+use harness::bench::{self, Sampler, Stats};
+
+// Benchmarks every registered day's parts against its data.txt.
+//
+// `cargo bench -p aoc -- 2022/day12` only runs benchmarks whose name contains
+// `2022/day12`. `AOC_BENCH_SECS` sets the measurement time per benchmark. Results
+// are compared against the previous run, saved in target/aoc-bench, and
+// totalled by each day's tags.
+fn main() {
+    let filters: Vec<String> = env::args().skip(1).filter(|a| !a.starts_with("--")).collect();
+    let mut sampler = Sampler::default();
+    if let Some(secs) = env::var("AOC_BENCH_SECS").ok().and_then(|s| s.parse().ok()) {
+        sampler.measurement = Duration::from_secs_f64(secs);
+    }
+
+    let mut results = Vec::new();
+    let mut categories = Vec::new();
+    for solution in SOLUTIONS {
+        let names = [1, 2].map(|part| format!("{}/part{}", solution.name(), part));
+        let selected: Vec<u8> = (1..=2)
+            .filter(|&part| filters.is_empty() || filters.iter().any(|f| names[part as usize - 1].contains(f.as_str())))
+            .collect();
+        if selected.is_empty() {
+            continue;
+        }
+
+        let path = solution.input_path("data");
+        let input = match harness::get_input(&path) {
+            Ok(s) => s,
+            Err(e) => {
+                println!("{}\tskipped, {}: {}", solution.name(), path.display(), e);
+                continue;
+            }
+        };
+
+        for part in selected {
+            let name = names[part as usize - 1].clone();
+            let f = solution.part(part);
+            let stats = sampler.run(|| f(&mut input.lines()));
+            println!(
+                "{}\tmean {:?}\tstd dev {:?}",
+                name,
+                Duration::from_nanos(stats.mean() as u64),
+                Duration::from_nanos(stats.std_dev() as u64),
+            );
+            results.push((name, stats));
+            categories.push(solution.metadata.tags);
+        }
+    }
+
+    let dir = target_dir().join("aoc-bench");
+    let last_path = dir.join("last.tsv");
+    let mut run = bench::load_run(&last_path);
+    let tagged: Vec<(&str, &Stats)> = results
+        .iter()
+        .zip(&categories)
+        .flat_map(|((_, stats), tags)| match tags.is_empty() {
+            true => vec![("untagged", stats)],
+            false => tags.iter().map(|tag| (*tag, stats)).collect(),
+        })
+        .collect();
+    let report = format!("{}\n{}", bench::markdown_report(&results, &run), bench::category_report(&tagged));
+    println!("\n{}", report);
+
+    for (name, stats) in &results {
+        run.insert(name.clone(), stats.summary());
+    }
+    let saved = bench::save_run(&last_path, &run).and_then(|_| fs::write(dir.join("report.md"), &report));
+    if let Err(e) = saved {
+        panic!("Error saving benchmark results: {}", e);
+    }
+}
+
+fn target_dir() -> PathBuf {
+    match env::var_os("CARGO_TARGET_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => match aoc::scaffold::root() {
+            Ok(root) => root.join("target"),
+            Err(e) => panic!("Error finding the workspace: {}", e),
+        },
+    }
+}
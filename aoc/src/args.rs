@@ -0,0 +1,84 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+/// Command line split into positional arguments, `--name value` (or
+/// `--name=value`) options and `--name` flags.
+pub struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+    flags: HashSet<String>,
+}
+
+impl Args {
+    /// Options named in `flags` never take a value, so a positional argument
+    /// can follow them.
+    pub fn parse(args: impl Iterator<Item = String>, flags: &[&str]) -> Args {
+        let mut parsed = Args { positional: Vec::new(), options: HashMap::new(), flags: HashSet::new() };
+        let mut args = args.peekable();
+        while let Some(arg) = args.next() {
+            let Some(name) = arg.strip_prefix("--") else {
+                parsed.positional.push(arg);
+                continue;
+            };
+            if let Some((name, value)) = name.split_once('=') {
+                parsed.options.insert(name.to_string(), value.to_string());
+            } else if flags.contains(&name) {
+                parsed.flags.insert(name.to_string());
+            } else {
+                let value = args.next_if(|a| !a.starts_with("--")).unwrap_or_default();
+                parsed.options.insert(name.to_string(), value);
+            }
+        }
+        parsed
+    }
+
+    pub fn positional(&self, index: usize) -> Option<&str> {
+        self.positional.get(index).map(|s| s.as_str())
+    }
+
+    pub fn required<T: FromStr>(&self, index: usize, name: &str) -> Result<T, String> {
+        let raw = self.positional(index).ok_or_else(|| format!("Missing <{}>", name))?;
+        raw.parse().map_err(|_| format!("Invalid <{}>: {}", name, raw))
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.contains(name)
+    }
+
+    pub fn option<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        match self.options.get(name) {
+            Some(raw) => raw.parse().map(Some).map_err(|_| format!("Invalid --{}: {}", name, raw)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Args {
+        Args::parse(args.split_whitespace().map(String::from), &["serial", "accept"])
+    }
+
+    #[test]
+    fn reads_a_positional_after_a_flag() {
+        let args = parse("run-all --serial 2022 --jobs 4");
+        assert_eq!((args.positional(0), args.positional(1)), (Some("run-all"), Some("2022")));
+        assert!(args.flag("serial"));
+        assert_eq!(args.option("jobs"), Ok(Some(4)));
+        assert!(!args.flag("accept"));
+    }
+
+    #[test]
+    fn reads_option_values_after_a_space_or_an_equals_sign() {
+        let args = parse("new --template grid 5 --size=60");
+        assert_eq!(args.option::<String>("template"), Ok(Some(String::from("grid"))));
+        assert_eq!(args.option("size"), Ok(Some(60)));
+        assert_eq!(args.positional(1), Some("5"));
+        assert_eq!(args.option::<u8>("seed"), Ok(None));
+        assert_eq!(parse("generate --size").option::<usize>("size"), Err(String::from("Invalid --size: ")));
+    }
+}
@@ -0,0 +1,25 @@
+use std::{env, time::Duration};
+
+use aoc::{args::Args, mock::{self, Mock}, registry};
+
+const USAGE: &str = "Usage:
+    aoc-mock [--port <port>] [--cooldown <seconds>]";
+
+fn main() {
+    let args = Args::parse(env::args().skip(1), &[]);
+    if args.positional(0).is_some() {
+        exit(USAGE);
+    }
+    let (port, cooldown) = match (args.option("port"), args.option("cooldown")) {
+        (Ok(port), Ok(cooldown)) => (port.unwrap_or(mock::DEFAULT_PORT), Duration::from_secs(cooldown.unwrap_or(60))),
+        (Err(e), _) | (_, Err(e)) => exit(&e),
+    };
+    if let Err(e) = Mock::new(registry::SOLUTIONS, cooldown).serve(port) {
+        exit(&format!("Error serving on port {}: {}", port, e));
+    }
+}
+
+fn exit(message: &str) -> ! {
+    eprintln!("{}", message);
+    std::process::exit(1);
+}
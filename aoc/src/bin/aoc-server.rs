@@ -0,0 +1,25 @@
+use std::env;
+
+use aoc::{args::Args, registry, server};
+
+const USAGE: &str = "Usage:
+    aoc-server [--port <port>]";
+
+fn main() {
+    let args = Args::parse(env::args().skip(1), &[]);
+    if args.positional(0).is_some() {
+        exit(USAGE);
+    }
+    let port = match args.option("port") {
+        Ok(port) => port.unwrap_or(server::DEFAULT_PORT),
+        Err(e) => exit(&e),
+    };
+    if let Err(e) = server::serve(port, registry::SOLUTIONS) {
+        exit(&format!("Error serving on port {}: {}", port, e));
+    }
+}
+
+fn exit(message: &str) -> ! {
+    eprintln!("{}", message);
+    std::process::exit(1);
+}
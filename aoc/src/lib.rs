@@ -0,0 +1,7 @@
+pub mod args;
+pub mod mock;
+pub mod pool;
+pub mod registry;
+pub mod scaffold;
+pub mod server;
+pub mod submit;
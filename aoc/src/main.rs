@@ -0,0 +1,289 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use aoc::{
+    args::Args,
+    pool, registry, scaffold,
+    submit::{self, Attempts, Outcome},
+};
+use harness::{
+    generate::Rng,
+    snapshot,
+    solution::{Answers, ANSWERS_FILE, INPUTS},
+    Solution,
+};
+
+const DEFAULT_YEAR: u16 = 2022;
+const DEFAULT_GENERATE_SIZE: usize = 100;
+/// Options that never take a value.
+const FLAGS: &[&str] = &["serial", "accept"];
+
+const USAGE: &str = "Usage:
+    aoc new [<year>] <day> [--template <template>]
+    aoc run [<year>] <day>
+    aoc run-all [<year>] [--jobs <jobs>] [--serial]
+    aoc repl [<year>] <day> [--input <input>]
+    aoc generate [<year>] <day> [--size <size>] [--seed <seed>]
+    aoc list [<year>] [--tag <tag>]
+    aoc submit [<year>] <day> <part> [--answer <answer>] [--endpoint <url>]
+    aoc snapshots [--accept]
+
+<year> defaults to the latest year with a registered day.";
+
+fn main() {
+    let args = Args::parse(env::args().skip(1), FLAGS);
+    let result = match args.positional(0) {
+        Some("new") => new(&args),
+        Some("run") => run(&args),
+        Some("run-all") => run_all(&args),
+        Some("repl") => repl(&args),
+        Some("generate") => generate(&args),
+        Some("list") => list(&args),
+        Some("submit") => submit(&args),
+        Some("snapshots") => snapshots(&args),
+        _ => Err(String::from(USAGE)),
+    };
+    if let Err(e) = result {
+        exit(&e);
+    }
+}
+
+fn exit(message: &str) -> ! {
+    eprintln!("{}", message);
+    std::process::exit(1);
+}
+
+/// Reads `[<year>] <day>` starting at positional argument `index`, followed
+/// by `trailing` more positional arguments.
+fn year_day_args(args: &Args, index: usize, trailing: usize) -> Result<(u16, u8), String> {
+    let (year, day) = match args.positional(index + 1 + trailing) {
+        Some(_) => (args.required(index, "year")?, args.required(index + 1, "day")?),
+        None => {
+            let year = registry::latest_year(registry::SOLUTIONS).unwrap_or(DEFAULT_YEAR);
+            (year, args.required(index, "day")?)
+        }
+    };
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day must be between 1 and 25, got {}", day));
+    }
+    Ok((year, day))
+}
+
+fn new(args: &Args) -> Result<(), String> {
+    let (year, day) = year_day_args(args, 1, 0)?;
+    let template: String = args.option("template")?.unwrap_or_else(|| scaffold::DEFAULT_TEMPLATE.to_string());
+    let root = scaffold::root().map_err(|e| format!("Error finding the workspace: {}", e))?;
+    scaffold::new_day(&root, year, day, &template).map_err(|e| e.to_string())
+}
+
+fn solution(args: &Args) -> Result<&'static Solution, String> {
+    find_solution(year_day_args(args, 1, 0)?)
+}
+
+fn find_solution((year, day): (u16, u8)) -> Result<&'static Solution, String> {
+    registry::find(registry::SOLUTIONS, year, day)
+        .ok_or_else(|| format!("Day {} of {} is not registered, create it with `aoc new {} {}`", day, year, year, day))
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    solution(args)?.run();
+    Ok(())
+}
+
+/// Runs every part of every day in a year, in parallel unless `--serial` is
+/// given. Reports are printed in day order either way.
+fn run_all(args: &Args) -> Result<(), String> {
+    let year = match args.positional(1) {
+        Some(_) => args.required(1, "year")?,
+        None => registry::latest_year(registry::SOLUTIONS).unwrap_or(DEFAULT_YEAR),
+    };
+    let threads = match args.flag("serial") {
+        true => 1,
+        false => match args.option::<usize>("jobs")? {
+            Some(jobs) => jobs.max(1),
+            None => thread::available_parallelism().map_or(1, |n| n.get()),
+        },
+    };
+
+    let mut jobs = Vec::new();
+    for solution in registry::SOLUTIONS.iter().filter(|s| s.year == year) {
+        for part in [1, 2] {
+            for input in INPUTS {
+                let first = part == 1 && input == INPUTS[0];
+                jobs.push(move || {
+                    let heading = match first {
+                        true => format!("\n======\n{} DAY {}\n======\n", solution.year, solution.day),
+                        false => String::new(),
+                    };
+                    heading + &solution.report(part, input)
+                });
+            }
+        }
+    }
+    if jobs.is_empty() {
+        return Err(format!("No days are registered for {}", year));
+    }
+
+    let runs = jobs.len();
+    let start_time = Instant::now();
+    pool::run_ordered(jobs, threads, |report| print!("{}", report));
+    println!("\nFinished {} runs in {:?} on {} thread(s)", runs, start_time.elapsed(), threads.min(runs));
+    Ok(())
+}
+
+fn repl(args: &Args) -> Result<(), String> {
+    let solution = solution(args)?;
+    let repl = solution.repl.ok_or_else(|| format!("{} has no REPL", solution.name()))?;
+    let input: String = args.option("input")?.unwrap_or_else(|| String::from("example"));
+    let path = solution.input_path(&input);
+    let str = harness::get_input(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    repl(&mut str.lines()).map_err(|e| e.to_string())
+}
+
+fn generate(args: &Args) -> Result<(), String> {
+    let solution = solution(args)?;
+    let generate = solution.generate.ok_or_else(|| format!("{} has no generator", solution.name()))?;
+    let size = args.option("size")?.unwrap_or(DEFAULT_GENERATE_SIZE);
+    let seed = match args.option("seed")? {
+        Some(seed) => seed,
+        None => {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).map_err(|e| e.to_string())?;
+            let seed = now.as_nanos() as u64;
+            eprintln!("Seed: {}", seed);
+            seed
+        }
+    };
+    print!("{}", generate(&mut Rng::new(seed), size));
+    Ok(())
+}
+
+/// Submits an answer, solving the day's `data.txt` for it unless one is
+/// given, and records how it went in the day's `attempts.txt`.
+fn submit(args: &Args) -> Result<(), String> {
+    let year_day = year_day_args(args, 1, 1)?;
+    let solution = find_solution(year_day)?;
+    let part: u8 = args.required(if args.positional(3).is_some() { 3 } else { 2 }, "part")?;
+    if part != 1 && part != 2 {
+        return Err(format!("Part must be 1 or 2, got {}", part));
+    }
+    let endpoint: String = match args.option("endpoint")? {
+        Some(endpoint) => endpoint,
+        None => env::var("AOC_ENDPOINT").map_err(|_| String::from("Set --endpoint or AOC_ENDPOINT to where answers go"))?,
+    };
+    let answer: String = match args.option("answer")? {
+        Some(answer) => answer,
+        None => {
+            let path = solution.input_path("data");
+            let input = harness::get_input(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            let (answer, _) = solution
+                .try_solve(part, &input)
+                .map_err(|message| format!("{} part {} panicked: {}", solution.name(), part, message))?;
+            answer.map_err(|e| e.with_path(&path).to_string())?
+        }
+    };
+
+    let mut attempts = Attempts::load(solution);
+    attempts.check(part, &answer).map_err(|e| format!("Not submitting: {}", e))?;
+    let session = env::var("AOC_SESSION").ok();
+    let outcome = submit::submit(&endpoint, session.as_deref(), solution, part, &answer).map_err(|e| e.to_string())?;
+    println!("{} part {}: {} is {}", solution.name(), part, answer, outcome);
+    attempts.record(part, &answer, outcome.clone()).map_err(|e| e.to_string())?;
+    if outcome == Outcome::Correct {
+        let path = Path::new(solution.dir).join(ANSWERS_FILE);
+        Answers::save(&path, "data", part, &answer).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Lists snapshots left pending by failed snapshot tests, with diffs, or
+/// accepts them all with `--accept`.
+fn snapshots(args: &Args) -> Result<(), String> {
+    let accept = args.flag("accept");
+    let pending: Vec<PathBuf> = registry::SOLUTIONS.iter().flat_map(|s| snapshot::pending(Path::new(s.dir))).collect();
+    if pending.is_empty() {
+        println!("No pending snapshots");
+    }
+    for path in pending {
+        if accept {
+            let path = snapshot::accept(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            println!("accepted\t{}", path.display());
+            continue;
+        }
+        let actual = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let expected = fs::read_to_string(path.with_extension("")).unwrap_or_default();
+        println!("{}\n{}\n", path.display(), snapshot::diff(&expected, &actual));
+    }
+    Ok(())
+}
+
+/// Lists registered days with their metadata, optionally only those in one
+/// year or with one tag.
+fn list(args: &Args) -> Result<(), String> {
+    let year: Option<u16> = match args.positional(1) {
+        Some(_) => Some(args.required(1, "year")?),
+        None => None,
+    };
+    let tag: Option<String> = args.option("tag")?;
+    let solutions = listed(registry::SOLUTIONS, year, tag.as_deref());
+    let width = solutions.iter().map(|s| s.metadata.title.len()).max().unwrap_or(0);
+    for solution in solutions {
+        let metadata = &solution.metadata;
+        println!(
+            "{:<10} {:<width$} {} {:<28} {}",
+            solution.name(),
+            metadata.title,
+            difficulty(metadata.difficulty),
+            metadata.tags.join(", "),
+            metadata.algorithms.join(", "),
+            width = width,
+        );
+    }
+    Ok(())
+}
+
+/// `difficulty` out of 5 as stars, all dots when it is not rated.
+/// The days `list` shows, in registry order.
+fn listed<'a>(solutions: &'a [Solution], year: Option<u16>, tag: Option<&str>) -> Vec<&'a Solution> {
+    solutions
+        .iter()
+        .filter(|s| year.is_none_or(|year| s.year == year))
+        .filter(|s| tag.is_none_or(|tag| s.metadata.has_tag(tag)))
+        .collect()
+}
+
+fn difficulty(difficulty: u8) -> String {
+    let stars = difficulty.min(5) as usize;
+    format!("{}{}", "*".repeat(stars), ".".repeat(5 - stars))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days(solutions: Vec<&Solution>) -> Vec<u8> {
+        solutions.iter().map(|s| s.day).collect()
+    }
+
+    #[test]
+    fn lists_days_by_year_and_tag() {
+        let solutions = registry::SOLUTIONS;
+        assert_eq!(listed(solutions, None, None).len(), solutions.len());
+        assert_eq!(listed(solutions, Some(2022), None).len(), solutions.len());
+        assert!(listed(solutions, Some(2015), None).is_empty());
+        let grid = days(listed(solutions, None, Some("grid")));
+        assert!(grid.contains(&12) && grid.contains(&14) && !grid.contains(&1), "{:?}", grid);
+        assert_eq!(days(listed(solutions, Some(2022), Some("GRID"))), grid);
+        assert!(listed(solutions, None, Some("gri")).is_empty());
+    }
+
+    #[test]
+    fn draws_difficulty_as_five_stars() {
+        assert_eq!(difficulty(0), ".....");
+        assert_eq!(difficulty(3), "***..");
+        assert_eq!(difficulty(9), "*****");
+    }
+}
@@ -0,0 +1,130 @@
+use std::{
+    collections::HashSet,
+    io::{self, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use harness::{
+    solution::{Answers, ANSWERS_FILE},
+    Solution,
+};
+
+use crate::{
+    registry,
+    server::{self, Request},
+    submit::form_decode,
+};
+
+pub const DEFAULT_PORT: u16 = 8023;
+
+/// Stand-in for the puzzle site's answer form, so `aoc submit` can be tried
+/// without it. Answers are judged against each day's `answers.txt` and the
+/// replies are worded like the real site's.
+pub struct Mock {
+    solutions: &'static [Solution],
+    /// How long a wrong answer locks out further answers.
+    cooldown: Duration,
+    locked_until: Option<Instant>,
+    solved: HashSet<(u16, u8, u8)>,
+}
+
+impl Mock {
+    pub fn new(solutions: &'static [Solution], cooldown: Duration) -> Mock {
+        Mock { solutions, cooldown, locked_until: None, solved: HashSet::new() }
+    }
+
+    /// Serves `POST /{year}/day/{n}/answer` on localhost, one request at a
+    /// time.
+    pub fn serve(&mut self, port: u16) -> io::Result<()> {
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        println!("Mock answers on http://{}", listener.local_addr()?);
+        self.serve_on(listener)
+    }
+
+    /// Like `serve`, on a listener already bound.
+    pub fn serve_on(&mut self, listener: TcpListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            if let Err(e) = self.handle(stream?) {
+                eprintln!("Error handling request: {}", e);
+            }
+        }
+        Ok(())
+    }
+
+    fn handle(&mut self, stream: TcpStream) -> io::Result<()> {
+        let mut reader = BufReader::new(&stream);
+        let (status, message) = match server::read_request(&mut reader)? {
+            Ok(request) => self.respond(&request),
+            Err(response) => (response.status, response.error.unwrap_or_default()),
+        };
+        let body = format!("<html><body><main><article><p>{}</p></article></main></body></html>\n", message);
+        write!(
+            &stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            server::reason(status),
+            body.len(),
+            body,
+        )
+    }
+
+    fn respond(&mut self, request: &Request) -> (u16, String) {
+        let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+        let (year, day) = match segments[..] {
+            [year, "day", day, "answer"] => match (year.parse::<u16>(), day.parse::<u8>()) {
+                (Ok(year), Ok(day)) => (year, day),
+                _ => return (400, String::from("Year and day must be numbers")),
+            },
+            _ => return (404, String::from("Expected POST /{year}/day/{n}/answer")),
+        };
+        if request.method != "POST" {
+            return (405, String::from("Only POST is supported"));
+        }
+        let Some(solution) = registry::find(self.solutions, year, day) else {
+            return (404, format!("Day {} of {} is not registered", day, year));
+        };
+
+        let form = String::from_utf8_lossy(&request.body);
+        let field = |name: &str| {
+            form.split('&').find_map(|pair| match pair.split_once('=') {
+                Some((key, value)) if key == name => Some(form_decode(value)),
+                _ => None,
+            })
+        };
+        let (Some(part), Some(answer)) = (field("level").and_then(|p| p.parse::<u8>().ok()), field("answer")) else {
+            return (400, String::from("Expected level and answer fields"));
+        };
+        let answers = Answers::load(&Path::new(solution.dir).join(ANSWERS_FILE));
+        let Some(expected) = answers.get("data", part) else {
+            return (404, format!("No known answer for {} part {}", solution.name(), part));
+        };
+
+        if self.solved.contains(&(year, day, part)) {
+            return (200, String::from("You don't seem to be solving the right level.  Did you already complete it?"));
+        }
+        let now = Instant::now();
+        if let Some(left) = self.locked_until.and_then(|until| until.checked_duration_since(now)) {
+            return (
+                200,
+                format!(
+                    "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {}s left to wait.",
+                    left.as_secs() + 1
+                ),
+            );
+        }
+        if answer.trim() == expected {
+            self.solved.insert((year, day, part));
+            return (200, String::from("That's the right answer!  You are one gold star closer to saving your vacation."));
+        }
+
+        self.locked_until = Some(now + self.cooldown);
+        let hint = match (answer.trim().parse::<i128>(), expected.parse::<i128>()) {
+            (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+            (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+            _ => "",
+        };
+        (200, format!("That's not the right answer{}.  Please wait before trying again.", hint))
+    }
+}
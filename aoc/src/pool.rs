@@ -0,0 +1,84 @@
+use std::{
+    collections::HashMap,
+    sync::{mpsc, Mutex},
+    thread,
+};
+
+/// Runs `jobs` on up to `threads` threads, handing each result to `done` in
+/// the order the jobs were given as soon as it and every job before it have
+/// finished. With one thread the jobs run one after another on the calling
+/// thread, so nothing else competes with them.
+pub fn run_ordered<T, F>(jobs: Vec<F>, threads: usize, mut done: impl FnMut(T))
+where
+    T: Send,
+    F: FnOnce() -> T + Send,
+{
+    let threads = threads.min(jobs.len());
+    if threads <= 1 {
+        jobs.into_iter().for_each(|job| done(job()));
+        return;
+    }
+
+    let queue = Mutex::new(jobs.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            let sender = sender.clone();
+            let queue = &queue;
+            scope.spawn(move || loop {
+                let Some((index, job)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                if sender.send((index, job())).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut finished = HashMap::new();
+        let mut next = 0;
+        for (index, result) in receiver {
+            finished.insert(index, result);
+            while let Some(result) = finished.remove(&next) {
+                done(result);
+                next += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn hands_results_over_in_job_order() {
+        let finished = Mutex::new(Vec::new());
+        // Later jobs sleep less, so they finish first.
+        let jobs: Vec<_> = (0..4u64)
+            .map(|i| {
+                let finished = &finished;
+                move || {
+                    thread::sleep(Duration::from_millis(50 * (4 - i)));
+                    finished.lock().unwrap().push(i);
+                    i
+                }
+            })
+            .collect();
+        let mut done = Vec::new();
+        run_ordered(jobs, 4, |i| done.push(i));
+        assert_eq!(finished.into_inner().unwrap(), [3, 2, 1, 0]);
+        assert_eq!(done, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn runs_on_the_calling_thread_with_one_thread() {
+        let caller = thread::current().id();
+        let mut done = Vec::new();
+        run_ordered((0..3).map(|i| move || (i, thread::current().id())).collect(), 1, |result| done.push(result));
+        assert_eq!(done, [(0, caller), (1, caller), (2, caller)]);
+    }
+}
@@ -0,0 +1,57 @@
+use harness::Solution;
+
+// Days known to the runner, kept up to date by `aoc new`.
+pub const SOLUTIONS: &[Solution] = &[
+    aoc2022_day1::SOLUTION,
+    aoc2022_day2::SOLUTION,
+    aoc2022_day3::SOLUTION,
+    aoc2022_day4::SOLUTION,
+    aoc2022_day5::SOLUTION,
+    aoc2022_day6::SOLUTION,
+    aoc2022_day7::SOLUTION,
+    aoc2022_day8::SOLUTION,
+    aoc2022_day9::SOLUTION,
+    aoc2022_day10::SOLUTION,
+    aoc2022_day11::SOLUTION,
+    aoc2022_day12::SOLUTION,
+    aoc2022_day13::SOLUTION,
+    aoc2022_day14::SOLUTION,
+    aoc2022_day15::SOLUTION,
+];
+
+/// The most recent year with a registered day.
+pub fn latest_year(solutions: &[Solution]) -> Option<u16> {
+    solutions.iter().map(|s| s.year).max()
+}
+
+pub fn find(solutions: &[Solution], year: u16, day: u8) -> Option<&Solution> {
+    solutions.iter().find(|s| s.year == year && s.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use harness::generate::Rng;
+
+    use super::*;
+
+    /// Kept small, as days 14 and 15 take seconds on inputs of real size.
+    #[test]
+    fn solves_every_generated_input() {
+        for solution in SOLUTIONS {
+            let Some(generate) = solution.generate else { continue };
+            for seed in [1, 2] {
+                for size in [0, 1, 3] {
+                    let input = generate(&mut Rng::new(seed), size);
+                    for part in [1, 2] {
+                        let (answer, _) = solution
+                            .try_solve(part, &input)
+                            .unwrap_or_else(|e| panic!("{} part {} panicked on seed {} size {}: {}", solution.name(), part, seed, size, e));
+                        if let Err(e) = answer {
+                            panic!("{} part {} failed on seed {} size {}:\n{}\n{}", solution.name(), part, seed, size, e, input);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use harness::solution::Answers;
+
+pub const DEFAULT_TEMPLATE: &str = "lines";
+
+/// Creates everything a new day needs in `<year>/day<day>` and registers it
+/// with the workspace and the runner. Files that already exist are left alone, so running it again
+/// only fills in what is missing.
+pub fn new_day(root: &Path, year: u16, day: u8, template: &str) -> io::Result<()> {
+    let template_path = root.join("templates").join(format!("{}.rs", template));
+    let source = fs::read_to_string(&template_path).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("Template {} not found, available: {}", template, templates(root).join(", ")),
+        )
+    })?;
+    let source = source
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string());
+
+    // Package names need the year too, as every year has a day 1.
+    let name = format!("aoc{}-day{}", year, day);
+    let dir_name = format!("{}/day{}", year, day);
+    let dir = root.join(&dir_name);
+    create(&dir.join("Cargo.toml"), &manifest(&name))?;
+    create(&dir.join("src").join("lib.rs"), &source)?;
+    create(&dir.join("src").join("main.rs"), &format!("fn main() {{\n    {}::SOLUTION.run();\n}}\n", crate_name(&name)))?;
+    create(&dir.join("data.txt"), "")?;
+    create(&dir.join("example.txt"), "")?;
+    create(&dir.join("answers.txt"), &Answers::template())?;
+
+    let runner = root.join("aoc");
+    insert_before(&root.join("Cargo.toml"), "]", &format!("    \"{}\",", dir_name))?;
+    append(&runner.join("Cargo.toml"), &format!("{} = {{ path = \"../{}\" }}", name, dir_name))?;
+    insert_before(&runner.join("src").join("registry.rs"), "];", &format!("    {}::SOLUTION,", crate_name(&name)))
+}
+
+pub fn templates(root: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(root.join("templates"))
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter_map(|e| e.file_name().to_str()?.strip_suffix(".rs").map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+fn manifest(name: &str) -> String {
+    format!(
+        "[package]
+name = \"{}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+harness = {{ path = \"../../harness\" }}
+",
+        name
+    )
+}
+
+/// How a package is referred to from Rust code.
+fn crate_name(package: &str) -> String {
+    package.replace('-', "_")
+}
+
+fn create(path: &Path, contents: &str) -> io::Result<()> {
+    if path.exists() {
+        println!("exists\t\t{}", path.display());
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    println!("created\t\t{}", path.display());
+    Ok(())
+}
+
+/// Adds `line` before the first line that is exactly `end`, unless it is
+/// already there.
+fn insert_before(path: &Path, end: &str, line: &str) -> io::Result<()> {
+    let contents = fs::read_to_string(path)?;
+    if contents.lines().any(|l| l == line) {
+        println!("registered\t{}", path.display());
+        return Ok(());
+    }
+
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let index = lines.iter().position(|l| *l == end).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, format!("No closing {} in {}", end, path.display()))
+    })?;
+    lines.insert(index, line);
+    fs::write(path, lines.join("\n") + "\n")?;
+    println!("updated\t\t{}", path.display());
+    Ok(())
+}
+
+fn append(path: &Path, line: &str) -> io::Result<()> {
+    let contents = fs::read_to_string(path)?;
+    if contents.lines().any(|l| l == line) {
+        println!("registered\t{}", path.display());
+        return Ok(());
+    }
+
+    let separator = if contents.ends_with('\n') { "" } else { "\n" };
+    fs::write(path, format!("{}{}{}\n", contents, separator, line))?;
+    println!("updated\t\t{}", path.display());
+    Ok(())
+}
+
+/// The workspace the current directory is in.
+pub fn root() -> io::Result<PathBuf> {
+    find_root(&env::current_dir()?)
+}
+
+/// The nearest directory from `dir` up whose `Cargo.toml` declares a
+/// `[workspace]`.
+pub fn find_root(dir: &Path) -> io::Result<PathBuf> {
+    for dir in dir.ancestors() {
+        let Ok(manifest) = fs::read_to_string(dir.join("Cargo.toml")) else { continue };
+        if manifest.lines().any(|l| l.trim() == "[workspace]") {
+            return Ok(dir.to_path_buf());
+        }
+    }
+    Err(io::Error::new(io::ErrorKind::NotFound, format!("No Cargo workspace in {} or above", dir.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_workspace_above_a_member() {
+        let member = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let root = member.parent().unwrap();
+        assert_eq!(find_root(&member.join("src")).unwrap(), root);
+        assert_eq!(find_root(root).unwrap(), root);
+        assert_eq!(find_root(Path::new("/")).unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+
+    /// A copy of the workspace skeleton `new_day` edits, in a fresh directory.
+    fn workspace(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("templates")).unwrap();
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        let templates = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().join("templates");
+        fs::copy(templates.join("lines.rs"), root.join("templates").join("lines.rs")).unwrap();
+        fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = [\n    \"aoc\",\n]\n").unwrap();
+        fs::write(root.join("aoc").join("Cargo.toml"), "[dependencies]\nharness = { path = \"../harness\" }\n").unwrap();
+        fs::write(root.join("aoc").join("src").join("registry.rs"), "pub const SOLUTIONS: &[Solution] = &[\n];\n").unwrap();
+        root
+    }
+
+    #[test]
+    fn adds_a_day_once_however_often_it_runs() {
+        let root = workspace("twice");
+        new_day(&root, 2023, 4, "lines").unwrap();
+        let lib = root.join("2023").join("day4").join("src").join("lib.rs");
+        assert!(fs::read_to_string(&lib).unwrap().contains("2023"));
+        fs::write(&lib, "// solved\n").unwrap();
+        let edited = ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/registry.rs"].map(|file| fs::read_to_string(root.join(file)).unwrap());
+
+        new_day(&root, 2023, 4, "lines").unwrap();
+        assert_eq!(["Cargo.toml", "aoc/Cargo.toml", "aoc/src/registry.rs"].map(|file| fs::read_to_string(root.join(file)).unwrap()), edited);
+        assert_eq!(fs::read_to_string(&lib).unwrap(), "// solved\n");
+        assert_eq!(edited[0], "[workspace]\nmembers = [\n    \"aoc\",\n    \"2023/day4\",\n]\n");
+        assert_eq!(edited[1].matches("aoc2023-day4 = { path = \"../2023/day4\" }").count(), 1);
+        assert_eq!(edited[2], "pub const SOLUTIONS: &[Solution] = &[\n    aoc2023_day4::SOLUTION,\n];\n");
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn names_the_templates_when_one_is_missing() {
+        let root = workspace("missing");
+        let error = new_day(&root, 2023, 4, "trees").unwrap_err();
+        assert_eq!(error.to_string(), "Template trees not found, available: lines");
+        assert!(!root.join("2023").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,238 @@
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    thread,
+};
+
+use harness::{json::json_string, Solution};
+
+use crate::registry;
+
+pub const DEFAULT_PORT: u16 = 8022;
+
+/// Largest request body accepted, well above any puzzle input.
+const MAX_BODY: usize = 16 * 1024 * 1024;
+
+/// Serves `POST /{year}/day/{n}/part/{p}` on localhost, solving the request
+/// body as the input. `/day/{n}/part/{p}` picks the latest year. Each
+/// connection is handled on its own thread.
+pub fn serve(port: u16, solutions: &'static [Solution]) -> io::Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    println!("Listening on http://{}", listener.local_addr()?);
+    for stream in listener.incoming() {
+        let stream = stream?;
+        thread::spawn(move || {
+            if let Err(e) = handle(stream, solutions) {
+                eprintln!("Error handling request: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
+
+pub(crate) struct Request {
+    pub(crate) method: String,
+    pub(crate) path: String,
+    pub(crate) body: Vec<u8>,
+}
+
+pub(crate) struct Response {
+    pub(crate) status: u16,
+    answer: Option<String>,
+    duration_ns: Option<u128>,
+    pub(crate) error: Option<String>,
+}
+
+impl Response {
+    fn error(status: u16, error: impl Into<String>) -> Response {
+        Response { status, answer: None, duration_ns: None, error: Some(error.into()) }
+    }
+
+    fn json(&self) -> String {
+        let string = |s: &Option<String>| s.as_deref().map_or(String::from("null"), json_string);
+        format!(
+            "{{\"answer\": {}, \"duration_ns\": {}, \"error\": {}}}\n",
+            string(&self.answer),
+            self.duration_ns.map_or(String::from("null"), |d| d.to_string()),
+            string(&self.error),
+        )
+    }
+}
+
+fn handle(stream: TcpStream, solutions: &[Solution]) -> io::Result<()> {
+    exchange(&mut BufReader::new(&stream), &mut &stream, solutions)
+}
+
+/// Reads one request from `reader` and writes the response to `writer`.
+fn exchange(reader: &mut impl BufRead, writer: &mut impl Write, solutions: &[Solution]) -> io::Result<()> {
+    let response = match read_request(reader)? {
+        Ok(request) => respond(&request, solutions),
+        Err(response) => response,
+    };
+    let body = response.json();
+    write!(
+        writer,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        body.len(),
+        body,
+    )
+}
+
+pub(crate) fn read_request(reader: &mut impl BufRead) -> io::Result<Result<Request, Response>> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Ok(Err(Response::error(400, "Malformed request line")));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut content_length = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                match value.trim().parse() {
+                    Ok(length) => content_length = length,
+                    Err(_) => return Ok(Err(Response::error(400, "Invalid Content-Length"))),
+                }
+            }
+        }
+    }
+    if content_length > MAX_BODY {
+        return Ok(Err(Response::error(413, "Input too large")));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    Ok(Ok(Request { method, path, body }))
+}
+
+fn respond(request: &Request, solutions: &[Solution]) -> Response {
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    let (year, day, part) = match segments[..] {
+        [year, "day", day, "part", part] => match (year.parse::<u16>(), day.parse::<u8>(), part.parse::<u8>()) {
+            (Ok(year), Ok(day), Ok(part)) => (year, day, part),
+            _ => return Response::error(400, "Year, day and part must be numbers"),
+        },
+        ["day", day, "part", part] => match (day.parse::<u8>(), part.parse::<u8>()) {
+            (Ok(day), Ok(part)) => (registry::latest_year(solutions).unwrap_or_default(), day, part),
+            _ => return Response::error(400, "Day and part must be numbers"),
+        },
+        _ => return Response::error(404, "Expected POST /{year}/day/{n}/part/{p}"),
+    };
+    if request.method != "POST" {
+        return Response::error(405, "Only POST is supported");
+    }
+    let Some(solution) = registry::find(solutions, year, day) else {
+        return Response::error(404, format!("Day {} of {} is not registered", day, year));
+    };
+    let Ok(input) = std::str::from_utf8(&request.body) else {
+        return Response::error(400, "Input must be UTF-8");
+    };
+
+    match solution.try_solve(part, input) {
+        Ok((Ok(answer), duration)) => Response {
+            status: 200,
+            answer: Some(answer),
+            duration_ns: Some(duration.as_nanos()),
+            error: None,
+        },
+        Ok((Err(e), _)) => Response::error(400, e.to_string()),
+        Err(e) if part != 1 && part != 2 => Response::error(404, e),
+        Err(e) => Response::error(500, e),
+    }
+}
+
+pub(crate) fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        _ => "Internal Server Error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    /// Sends `request` through the server and returns the status line and body
+    /// of the response.
+    fn exchange_raw(request: &str) -> (String, String) {
+        let mut response = Vec::new();
+        exchange(&mut Cursor::new(request), &mut response, registry::SOLUTIONS).unwrap();
+        let response = String::from_utf8(response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        assert!(head.contains(&format!("\r\nContent-Length: {}\r\n", body.len())), "{}", head);
+        (head.lines().next().unwrap().to_string(), body.to_string())
+    }
+
+    fn post(path: &str, body: &str) -> String {
+        format!("POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}", path, body.len(), body)
+    }
+
+    fn error(message: &str) -> String {
+        format!("{{\"answer\": null, \"duration_ns\": null, \"error\": {}}}\n", json_string(message))
+    }
+
+    #[test]
+    fn solves_the_body_as_input() {
+        let input = "1000\n2000\n\n4000\n";
+        for path in ["/2022/day/1/part/1", "/day/1/part/1"] {
+            let (status, body) = exchange_raw(&post(path, input));
+            assert_eq!(status, "HTTP/1.1 200 OK");
+            assert!(body.starts_with("{\"answer\": \"4000\", \"duration_ns\": "), "{}", body);
+            assert!(body.ends_with(", \"error\": null}\n"), "{}", body);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_requests() {
+        assert_eq!(exchange_raw("\r\n"), (String::from("HTTP/1.1 400 Bad Request"), error("Malformed request line")));
+        assert_eq!(
+            exchange_raw("POST /2022/day/1/part/1 HTTP/1.1\r\nContent-Length: lots\r\n\r\n"),
+            (String::from("HTTP/1.1 400 Bad Request"), error("Invalid Content-Length")),
+        );
+        assert_eq!(
+            exchange_raw(&post("/2022/day/one/part/1", "")),
+            (String::from("HTTP/1.1 400 Bad Request"), error("Year, day and part must be numbers")),
+        );
+        let (status, body) = exchange_raw(&post("/2022/day/1/part/1", "12a4\n"));
+        assert_eq!(status, "HTTP/1.1 400 Bad Request");
+        assert!(body.contains("Expected a number"), "{}", body);
+    }
+
+    #[test]
+    fn routes_only_posts_to_registered_days() {
+        let not_found = String::from("HTTP/1.1 404 Not Found");
+        assert_eq!(exchange_raw(&post("/", "")), (not_found.clone(), error("Expected POST /{year}/day/{n}/part/{p}")));
+        assert_eq!(exchange_raw(&post("/2022/day/26/part/1", "")), (not_found.clone(), error("Day 26 of 2022 is not registered")));
+        assert_eq!(exchange_raw(&post("/2022/day/1/part/3", "")), (not_found, error("2022/day1 has no part 3")));
+        assert_eq!(
+            exchange_raw("GET /2022/day/1/part/1 HTTP/1.1\r\n\r\n"),
+            (String::from("HTTP/1.1 405 Method Not Allowed"), error("Only POST is supported")),
+        );
+    }
+
+    #[test]
+    fn caps_the_body_size() {
+        let request = |length: usize| format!("POST /2022/day/1/part/1 HTTP/1.1\r\nContent-Length: {}\r\n\r\n", length);
+        assert_eq!(
+            exchange_raw(&request(MAX_BODY + 1)),
+            (String::from("HTTP/1.1 413 Payload Too Large"), error("Input too large")),
+        );
+        // A body of exactly `MAX_BODY` is read, and here runs out.
+        let error = read_request(&mut Cursor::new(request(MAX_BODY))).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,388 @@
+use std::{
+    fmt, fs,
+    io::{self, Read, Write},
+    net::TcpStream,
+    path::{Path, PathBuf},
+    str::{self, FromStr},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use harness::Solution;
+
+pub const ATTEMPTS_FILE: &str = "attempts.txt";
+
+/// How the puzzle site judged a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint which way.
+    Wrong,
+    /// Submitted too soon after a wrong answer, with how long is left to wait
+    /// when the site said.
+    RateLimited(Option<String>),
+    /// The part was already solved, so the answer was not checked.
+    AlreadySolved,
+    Unknown,
+}
+
+impl Outcome {
+    /// Reads the outcome from the response page, which only says it in prose.
+    pub fn parse(page: &str) -> Outcome {
+        if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("answer too recently") {
+            let wait = page
+                .split_once(" left to wait")
+                .and_then(|(before, _)| before.rsplit_once("You have "))
+                .map(|(_, wait)| wait.to_string());
+            Outcome::RateLimited(wait)
+        } else if page.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if page.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if page.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else if page.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    /// Whether the answer is known to be wrong, so never worth sending again.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too-high"),
+            Outcome::TooLow => write!(f, "too-low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited(None) => write!(f, "rate-limited"),
+            Outcome::RateLimited(Some(wait)) => write!(f, "rate-limited ({} left)", wait),
+            Outcome::AlreadySolved => write!(f, "already-solved"),
+            Outcome::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Outcome, String> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too-high" => Ok(Outcome::TooHigh),
+            "too-low" => Ok(Outcome::TooLow),
+            "wrong" => Ok(Outcome::Wrong),
+            "already-solved" => Ok(Outcome::AlreadySolved),
+            "unknown" => Ok(Outcome::Unknown),
+            s if s.starts_with("rate-limited") => Ok(Outcome::RateLimited(None)),
+            s => Err(format!("Unknown outcome {}", s)),
+        }
+    }
+}
+
+/// One submitted answer.
+#[derive(Clone, Debug)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+}
+
+/// Every answer submitted for a day, kept in its `attempts.txt` as lines of
+/// `part<tab>answer<tab>outcome<tab>time`.
+#[derive(Debug, Default)]
+pub struct Attempts {
+    path: PathBuf,
+    pub attempts: Vec<Attempt>,
+}
+
+impl Attempts {
+    pub fn load(solution: &Solution) -> Attempts {
+        let path = Path::new(solution.dir).join(ATTEMPTS_FILE);
+        let attempts = fs::read_to_string(&path).map(|s| Attempts::parse(&s)).unwrap_or_default();
+        Attempts { path, attempts }
+    }
+
+    fn parse(s: &str) -> Vec<Attempt> {
+        s.lines()
+            .filter_map(|line| {
+                let mut fields = line.split('\t');
+                Some(Attempt {
+                    part: fields.next()?.parse().ok()?,
+                    answer: unescape(fields.next()?),
+                    outcome: fields.next()?.parse().ok()?,
+                    time: fields.next()?.parse().ok()?,
+                })
+            })
+            .collect()
+    }
+
+    /// Why `answer` should not be sent for `part`, judging by earlier
+    /// attempts: it was rejected before, the part is already solved, or it is
+    /// past an answer already known to be too high or too low.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), String> {
+        let attempts: Vec<&Attempt> = self.attempts.iter().filter(|a| a.part == part).collect();
+        if let Some(correct) = attempts.iter().find(|a| a.outcome == Outcome::Correct) {
+            return Err(format!("Part {} is already solved, the answer was {}", part, correct.answer));
+        }
+        if let Some(rejected) = attempts.iter().find(|a| a.answer == answer && a.outcome.is_wrong()) {
+            return Err(format!("{} was already rejected as {}", answer, rejected.outcome));
+        }
+        let Ok(number) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        for attempt in attempts {
+            let Ok(bound) = attempt.answer.parse::<i128>() else { continue };
+            match attempt.outcome {
+                Outcome::TooHigh if number >= bound => return Err(format!("{} is too high, {} already was", answer, bound)),
+                Outcome::TooLow if number <= bound => return Err(format!("{} is too low, {} already was", answer, bound)),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, outcome: Outcome) -> io::Result<()> {
+        let time = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        let line = format!("{}\t{}\t{}\t{}\n", part, escape(answer), outcome, time);
+        let mut file = fs::OpenOptions::new().create(true).append(true).open(&self.path)?;
+        file.write_all(line.as_bytes())?;
+        self.attempts.push(Attempt { part, answer: answer.to_string(), outcome, time });
+        Ok(())
+    }
+}
+
+/// Escapes the tabs, newlines and backslashes in an answer, such as a
+/// multi-line drawing, so it fits on one line of the attempts file.
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n")
+}
+
+fn unescape(field: &str) -> String {
+    let mut answer = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some(escaped @ ('\\' | 't' | 'n'))) => {
+                chars.next();
+                answer.push(match escaped {
+                    't' => '\t',
+                    'n' => '\n',
+                    _ => '\\',
+                });
+            }
+            _ => answer.push(c),
+        }
+    }
+    answer
+}
+
+/// Posts `answer` for a part to `endpoint` the way the puzzle site's answer
+/// form does, and reads the outcome from the reply. Only plain `http://`
+/// endpoints are supported, such as `aoc-mock` or a local proxy.
+pub fn submit(endpoint: &str, session: Option<&str>, solution: &Solution, part: u8, answer: &str) -> io::Result<Outcome> {
+    let path = format!("/{}/day/{}/answer", solution.year, solution.day);
+    let body = format!("level={}&answer={}", part, form_encode(answer));
+    let (status, page) = post_form(endpoint, &path, &body, session)?;
+    match status {
+        200 => Ok(Outcome::parse(&page)),
+        _ => Err(io::Error::other(format!("{} replied {}: {}", endpoint, status, text(&page)))),
+    }
+}
+
+/// `page` without its HTML tags.
+fn text(page: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in page.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.trim().to_string()
+}
+
+fn post_form(endpoint: &str, path: &str, body: &str, session: Option<&str>) -> io::Result<(u16, String)> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidInput, format!("{}: {}", message, endpoint));
+    let rest = endpoint.strip_prefix("http://").ok_or_else(|| invalid("Expected an http:// endpoint"))?;
+    let (host, base) = match rest.find('/') {
+        Some(i) => (&rest[..i], rest[i..].trim_end_matches('/')),
+        None => (rest, ""),
+    };
+    if host.is_empty() {
+        return Err(invalid("Expected a host"));
+    }
+    let address = if host.contains(':') { host.to_string() } else { format!("{}:80", host) };
+
+    let mut stream = TcpStream::connect(address)?;
+    stream.set_read_timeout(Some(Duration::from_secs(30)))?;
+    let cookie = session.map_or(String::new(), |s| format!("Cookie: session={}\r\n", s));
+    write!(
+        stream,
+        "POST {}{} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/x-www-form-urlencoded\r\nContent-Length: {}\r\n{}Connection: close\r\n\r\n{}",
+        base,
+        path,
+        host,
+        body.len(),
+        cookie,
+        body,
+    )?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    let (head, page) = response.split_once("\r\n\r\n").unwrap_or((&response, ""));
+    let status = head
+        .split_whitespace()
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Malformed HTTP response"))?;
+    Ok((status, page.to_string()))
+}
+
+fn form_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            b' ' => String::from("+"),
+            b => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Undoes `form_encode`, leaving malformed escapes as they are.
+pub fn form_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => match bytes.get(i + 1..i + 3).and_then(|hex| u8::from_str_radix(str::from_utf8(hex).ok()?, 16).ok()) {
+                Some(b) => {
+                    decoded.push(b);
+                    i += 2;
+                }
+                None => decoded.push(b'%'),
+            },
+            b => decoded.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, net::TcpListener, process, thread};
+
+    use super::*;
+    use crate::mock::Mock;
+
+    /// Serves a mock judging day 1 of 2022 against `expected` for part 1, and
+    /// returns its endpoint.
+    fn mock(name: &str, expected: &str, cooldown: Duration) -> String {
+        let dir = env::temp_dir().join(format!("aoc-submit-{}-{}", process::id(), name));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("answers.txt"), format!("data part1: {}\n", expected)).unwrap();
+        let dir: &'static str = Box::leak(dir.to_string_lossy().into_owned().into_boxed_str());
+        let solutions: &'static [Solution] = Box::leak(Box::new([Solution { dir, ..aoc2022_day1::SOLUTION }]));
+
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        thread::spawn(move || Mock::new(solutions, cooldown).serve_on(listener));
+        endpoint
+    }
+
+    fn attempts(s: &str) -> Attempts {
+        Attempts { path: PathBuf::new(), attempts: Attempts::parse(s) }
+    }
+
+    #[test]
+    fn reads_every_outcome_from_the_page() {
+        let pages = [
+            ("That's the right answer!  You are one gold star closer to saving your vacation.", Outcome::Correct),
+            ("That's not the right answer; your answer is too high.  If you're stuck, ...", Outcome::TooHigh),
+            ("That's not the right answer; your answer is too low.  If you're stuck, ...", Outcome::TooLow),
+            ("That's not the right answer.  If you're stuck, ...", Outcome::Wrong),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.",
+                Outcome::RateLimited(Some(String::from("34s"))),
+            ),
+            ("You gave an answer too recently.", Outcome::RateLimited(None)),
+            ("You don't seem to be solving the right level.  Did you already complete it?", Outcome::AlreadySolved),
+            ("<html><body>Not found</body></html>", Outcome::Unknown),
+        ];
+        for (page, outcome) in pages {
+            assert_eq!(Outcome::parse(&format!("<article><p>{}</p></article>", page)), outcome, "{}", page);
+        }
+    }
+
+    #[test]
+    fn refuses_answers_known_to_be_wrong() {
+        let attempts = attempts("1\t100\ttoo-high\t0\n1\t40\ttoo-low\t0\n1\tabc\twrong\t0\n2\t7\tcorrect\t0\n");
+        assert_eq!(attempts.check(1, "100"), Err(String::from("100 was already rejected as too-high")));
+        assert_eq!(attempts.check(1, "abc"), Err(String::from("abc was already rejected as wrong")));
+        assert_eq!(attempts.check(1, "150"), Err(String::from("150 is too high, 100 already was")));
+        assert_eq!(attempts.check(1, "30"), Err(String::from("30 is too low, 40 already was")));
+        assert_eq!(attempts.check(1, "70"), Ok(()));
+        assert_eq!(attempts.check(1, "xyz"), Ok(()));
+        assert_eq!(attempts.check(2, "8"), Err(String::from("Part 2 is already solved, the answer was 7")));
+    }
+
+    #[test]
+    fn records_answers_with_tabs_and_newlines_on_one_line() {
+        let dir = env::temp_dir().join(format!("aoc-submit-{}-attempts", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut attempts = Attempts { path: dir.join(ATTEMPTS_FILE), attempts: Vec::new() };
+        let drawing = "#..#\n.\t.\\n";
+        attempts.record(2, drawing, Outcome::Wrong).unwrap();
+        attempts.record(1, "42", Outcome::Correct).unwrap();
+
+        let written = fs::read_to_string(dir.join(ATTEMPTS_FILE)).unwrap();
+        assert_eq!(written.lines().count(), 2);
+        assert!(written.starts_with("2\t#..#\\n.\\t.\\\\n\twrong\t"), "{}", written);
+        let answers: Vec<String> = Attempts::parse(&written).into_iter().map(|a| a.answer).collect();
+        assert_eq!(answers, [drawing, "42"]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn decodes_what_it_encodes() {
+        for answer in ["12345", "-12 34", "a+b&c=d%", "JZ.GL_~", "héllo"] {
+            assert_eq!(form_decode(&form_encode(answer)), answer);
+        }
+        assert_eq!(form_encode("a+b&c=d%"), "a%2Bb%26c%3Dd%25");
+        assert_eq!(form_decode("100%+%zz%4"), "100% %zz%4");
+    }
+
+    #[test]
+    fn submits_to_the_mock() {
+        let endpoint = mock("judged", "100", Duration::ZERO);
+        let solution = &aoc2022_day1::SOLUTION;
+        assert_eq!(submit(&endpoint, Some("session"), solution, 1, "1").unwrap(), Outcome::TooLow);
+        assert_eq!(submit(&endpoint, None, solution, 1, "500").unwrap(), Outcome::TooHigh);
+        assert_eq!(submit(&endpoint, None, solution, 1, "1 00").unwrap(), Outcome::Wrong);
+        assert_eq!(submit(&endpoint, None, solution, 1, "100").unwrap(), Outcome::Correct);
+        assert_eq!(submit(&endpoint, None, solution, 1, "100").unwrap(), Outcome::AlreadySolved);
+        let error = submit(&endpoint, None, solution, 2, "100").unwrap_err();
+        assert!(error.to_string().ends_with("replied 404: No known answer for 2022/day1 part 2"), "{}", error);
+
+        let endpoint = mock("locked", "100", Duration::from_secs(60));
+        assert_eq!(submit(&endpoint, None, solution, 1, "1").unwrap(), Outcome::TooLow);
+        assert!(matches!(submit(&endpoint, None, solution, 1, "100").unwrap(), Outcome::RateLimited(Some(_))));
+    }
+}
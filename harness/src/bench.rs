@@ -0,0 +1,291 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+    fs, io,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// Warms a function up, then times it over repeated samples.
+///
+/// Each sample runs the function enough times to fill its share of the
+/// measurement time. Slow functions get fewer samples instead of blowing the
+/// time budget, but never fewer than `min_samples`.
+#[derive(Clone, Debug)]
+pub struct Sampler {
+    pub warm_up: Duration,
+    pub measurement: Duration,
+    pub samples: usize,
+    pub min_samples: usize,
+}
+
+impl Default for Sampler {
+    fn default() -> Sampler {
+        Sampler {
+            warm_up: Duration::from_millis(500),
+            measurement: Duration::from_secs(3),
+            samples: 50,
+            min_samples: 5,
+        }
+    }
+}
+
+impl Sampler {
+    pub fn run<T>(&self, mut f: impl FnMut() -> T) -> Stats {
+        let start = Instant::now();
+        let mut warm_up_iterations = 0u32;
+        while warm_up_iterations == 0 || start.elapsed() < self.warm_up {
+            std::hint::black_box(f());
+            warm_up_iterations += 1;
+        }
+        let estimate = start.elapsed() / warm_up_iterations;
+
+        let budget = self.measurement.as_secs_f64();
+        let per_iteration = estimate.as_secs_f64().max(1e-9);
+        let samples = ((budget / per_iteration) as usize).clamp(self.min_samples.max(1), self.samples.max(1));
+        let iterations = ((budget / samples as f64 / per_iteration) as u32).max(1);
+
+        let times = (0..samples)
+            .map(|_| {
+                let start = Instant::now();
+                for _ in 0..iterations {
+                    std::hint::black_box(f());
+                }
+                start.elapsed().as_nanos() as f64 / iterations as f64
+            })
+            .collect();
+        Stats { times, iterations }
+    }
+}
+
+/// Per-iteration times of every sample, in nanoseconds.
+#[derive(Clone, Debug)]
+pub struct Stats {
+    pub times: Vec<f64>,
+    pub iterations: u32,
+}
+
+impl Stats {
+    pub fn mean(&self) -> f64 {
+        self.times.iter().sum::<f64>() / self.times.len() as f64
+    }
+
+    pub fn median(&self) -> f64 {
+        let mut sorted = self.times.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let mid = sorted.len() / 2;
+        if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2.0
+        } else {
+            sorted[mid]
+        }
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        if self.times.len() < 2 {
+            return 0.0;
+        }
+        let mean = self.mean();
+        let variance = self.times.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / (self.times.len() - 1) as f64;
+        variance.sqrt()
+    }
+
+    pub fn summary(&self) -> Summary {
+        Summary { mean: self.mean(), std_dev: self.std_dev(), samples: self.times.len() }
+    }
+}
+
+/// What is kept of a run to compare the next one against.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Summary {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub samples: usize,
+}
+
+impl Summary {
+    fn std_error(&self) -> f64 {
+        self.std_dev / (self.samples.max(1) as f64).sqrt()
+    }
+}
+
+/// Changes smaller than this fraction of the old mean are reported as noise.
+pub const NOISE_THRESHOLD: f64 = 0.02;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Change {
+    Improved(f64),
+    Regressed(f64),
+    NoChange(f64),
+}
+
+impl Change {
+    /// A change only counts when it is bigger than the noise threshold and
+    /// than twice the combined standard error of both runs.
+    pub fn between(old: &Summary, new: &Summary) -> Change {
+        let relative = (new.mean - old.mean) / old.mean;
+        let error = 2.0 * (old.std_error().powi(2) + new.std_error().powi(2)).sqrt();
+        if relative.abs() < NOISE_THRESHOLD || (new.mean - old.mean).abs() < error {
+            Change::NoChange(relative)
+        } else if relative < 0.0 {
+            Change::Improved(relative)
+        } else {
+            Change::Regressed(relative)
+        }
+    }
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Change::Improved(r) => write!(f, "{:+.1}% improved", r * 100.0),
+            Change::Regressed(r) => write!(f, "{:+.1}% regressed", r * 100.0),
+            Change::NoChange(r) => write!(f, "{:+.1}% no change", r * 100.0),
+        }
+    }
+}
+
+/// Reads a run saved by `save_run`, keyed by benchmark name.
+pub fn load_run(path: &Path) -> HashMap<String, Summary> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+    contents
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let name = fields.next()?;
+            let mean = fields.next()?.parse().ok()?;
+            let std_dev = fields.next()?.parse().ok()?;
+            let samples = fields.next()?.parse().ok()?;
+            Some((name.to_string(), Summary { mean, std_dev, samples }))
+        })
+        .collect()
+}
+
+pub fn save_run(path: &Path, run: &HashMap<String, Summary>) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut names: Vec<&String> = run.keys().collect();
+    names.sort();
+    let mut contents = String::new();
+    for name in names {
+        let s = &run[name];
+        writeln!(contents, "{}\t{}\t{}\t{}", name, s.mean, s.std_dev, s.samples).unwrap();
+    }
+    fs::write(path, contents)
+}
+
+fn format_ns(ns: f64) -> String {
+    format!("{:?}", Duration::from_nanos(ns.round() as u64))
+}
+
+pub fn markdown_report(results: &[(String, Stats)], last: &HashMap<String, Summary>) -> String {
+    let mut report = String::from("| Benchmark | Mean | Median | Std dev | Samples | Last mean | Change |\n");
+    report.push_str("|---|---:|---:|---:|---:|---:|---|\n");
+    for (name, stats) in results {
+        let summary = stats.summary();
+        let (last_mean, change) = match last.get(name) {
+            Some(old) => (format_ns(old.mean), Change::between(old, &summary).to_string()),
+            None => (String::from("-"), String::from("new")),
+        };
+        writeln!(
+            report,
+            "| {} | {} | {} | {} | {} x {} | {} | {} |",
+            name,
+            format_ns(summary.mean),
+            format_ns(stats.median()),
+            format_ns(summary.std_dev),
+            summary.samples,
+            stats.iterations,
+            last_mean,
+            change,
+        )
+        .unwrap();
+    }
+    report
+}
+
+/// Mean times summed by category. `entries` pairs a category with a
+/// benchmark's stats, so a benchmark in several categories counts in each.
+pub fn category_report(entries: &[(&str, &Stats)]) -> String {
+    let mut totals: BTreeMap<&str, (usize, f64)> = BTreeMap::new();
+    for (category, stats) in entries {
+        let total = totals.entry(category).or_default();
+        total.0 += 1;
+        total.1 += stats.mean();
+    }
+    let mut report = String::from("| Tag | Benchmarks | Total mean |\n|---|---:|---:|\n");
+    for (category, (count, mean)) in totals {
+        writeln!(report, "| {} | {} | {} |", category, count, format_ns(mean)).unwrap();
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    fn summary(mean: f64, std_dev: f64, samples: usize) -> Summary {
+        Summary { mean, std_dev, samples }
+    }
+
+    #[test]
+    fn summarises_samples() {
+        let stats = Stats { times: vec![4.0, 1.0, 3.0, 2.0], iterations: 1 };
+        assert_eq!(stats.mean(), 2.5);
+        assert_eq!(stats.median(), 2.5);
+        assert!((stats.std_dev() - (5.0f64 / 3.0).sqrt()).abs() < 1e-12);
+        assert_eq!(Stats { times: vec![3.0, 1.0, 2.0], iterations: 1 }.median(), 2.0);
+        assert_eq!(Stats { times: vec![7.0], iterations: 1 }.std_dev(), 0.0);
+    }
+
+    #[test]
+    fn counts_changes_beyond_the_noise() {
+        let old = summary(100.0, 1.0, 25);
+        assert_eq!(Change::between(&old, &summary(90.0, 1.0, 25)), Change::Improved(-0.1));
+        assert_eq!(Change::between(&old, &summary(110.0, 1.0, 25)), Change::Regressed(0.1));
+        // Under the 2% floor, however steady the samples.
+        assert_eq!(Change::between(&summary(100.0, 0.0, 25), &summary(101.0, 0.0, 25)), Change::NoChange(0.01));
+        // Over the floor, but within twice the standard error.
+        assert_eq!(Change::between(&summary(100.0, 50.0, 4), &summary(120.0, 50.0, 4)), Change::NoChange(0.2));
+        assert_eq!(Change::Improved(-0.1).to_string(), "-10.0% improved");
+        assert_eq!(Change::NoChange(0.2).to_string(), "+20.0% no change");
+    }
+
+    #[test]
+    fn loads_the_run_it_saved() {
+        let dir = env::temp_dir().join(format!("aoc-bench-{}", process::id()));
+        let path = dir.join("runs").join("last.tsv");
+        assert!(load_run(&path).is_empty());
+
+        let run = HashMap::from([
+            (String::from("2022/day1 part1"), summary(1234.5678, 0.1 + 0.2, 50)),
+            (String::from("2022/day2 part2"), summary(1e9, 0.0, 5)),
+        ]);
+        save_run(&path, &run).unwrap();
+        assert_eq!(load_run(&path), run);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn reports_each_benchmark_against_the_last_run() {
+        let results = [
+            (String::from("2022/day1 part1"), Stats { times: vec![1000.0, 3000.0], iterations: 10 }),
+            (String::from("2022/day2 part1"), Stats { times: vec![500.0], iterations: 1 }),
+        ];
+        let last = HashMap::from([(String::from("2022/day1 part1"), summary(10000.0, 0.0, 2))]);
+        assert_eq!(
+            markdown_report(&results, &last),
+            concat!(
+                "| Benchmark | Mean | Median | Std dev | Samples | Last mean | Change |\n",
+                "|---|---:|---:|---:|---:|---:|---|\n",
+                "| 2022/day1 part1 | 2µs | 2µs | 1.414µs | 2 x 10 | 10µs | -80.0% improved |\n",
+                "| 2022/day2 part1 | 500ns | 500ns | 0ns | 1 x 1 | - | new |\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::{
+    error::Error,
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+/// Bad input, pinned to the line and column it was found at.
+///
+/// Renders like a compiler diagnostic, with the offending line and a caret
+/// under the part that could not be parsed:
+///
+/// ```text
+/// error: Expected a number, found `12a4`
+///  --> 2022/day1/data.txt:3:1
+///   |
+/// 3 | 12a4
+///   | ^^^^
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AocError {
+    pub message: String,
+    /// The input file, once known. Parts only see lines, so the caller fills
+    /// this in with `with_path`.
+    pub path: Option<PathBuf>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, in characters.
+    pub column: usize,
+    /// Number of characters to underline.
+    pub len: usize,
+    pub excerpt: String,
+}
+
+impl AocError {
+    /// Error about `span` on the `index`th (0-based) line of the input.
+    ///
+    /// `span` should be a slice of `line`, so its position is exact. Anything
+    /// else is looked up by its text, falling back to the whole line.
+    pub fn at(index: usize, line: &str, span: &str, message: impl Into<String>) -> AocError {
+        let line_start = line.as_ptr() as usize;
+        let span_start = span.as_ptr() as usize;
+        let offset = if span_start >= line_start && span_start + span.len() <= line_start + line.len() {
+            Some(span_start - line_start)
+        } else {
+            line.find(span).filter(|_| !span.is_empty())
+        };
+        let (offset, len) = match offset {
+            Some(offset) => (offset, span.chars().count()),
+            None => (0, line.chars().count()),
+        };
+        AocError {
+            message: message.into(),
+            path: None,
+            line: index + 1,
+            column: line[..offset].chars().count() + 1,
+            len: len.max(1),
+            excerpt: line.to_string(),
+        }
+    }
+
+    /// Error about the whole `index`th (0-based) line.
+    pub fn line(index: usize, line: &str, message: impl Into<String>) -> AocError {
+        AocError::at(index, line, line, message)
+    }
+
+    /// Error about input that ended before line `index` (0-based) could be read.
+    pub fn end_of_input(index: usize, message: impl Into<String>) -> AocError {
+        AocError::at(index, "", "", message)
+    }
+
+    pub fn with_path(mut self, path: &Path) -> AocError {
+        self.path.get_or_insert_with(|| path.to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gutter = self.line.to_string().len();
+        let path = match &self.path {
+            Some(path) => path.display().to_string(),
+            None => String::from("<input>"),
+        };
+        writeln!(f, "error: {}", self.message)?;
+        writeln!(f, "{:gutter$}--> {}:{}:{}", "", path, self.line, self.column, gutter = gutter)?;
+        writeln!(f, "{:gutter$} |", "", gutter = gutter)?;
+        writeln!(f, "{} | {}", self.line, self.excerpt)?;
+        write!(f, "{:gutter$} | {}{}", "", " ".repeat(self.column - 1), "^".repeat(self.len), gutter = gutter)
+    }
+}
+
+impl Error for AocError {}
+
+impl From<AocError> for io::Error {
+    fn from(e: AocError) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn underlines_a_span_in_the_middle_of_a_line() {
+        let line = "12 34x 56";
+        let error = AocError::at(2, line, &line[3..6], "Expected a number, found `34x`");
+        assert_eq!(
+            error.to_string(),
+            "error: Expected a number, found `34x`\n --> <input>:3:4\n  |\n3 | 12 34x 56\n  |    ^^^"
+        );
+    }
+
+    #[test]
+    fn counts_columns_in_characters() {
+        let line = "é,x";
+        let error = AocError::at(0, line, &line[3..], "Expected a number, found `x`");
+        assert_eq!((error.column, error.len), (3, 1));
+        assert!(error.to_string().ends_with("1 | é,x\n  |   ^"));
+    }
+
+    #[test]
+    fn points_past_the_last_line_at_the_end_of_input() {
+        let error = AocError::end_of_input(4, "Expected 6 lines describing a monkey");
+        assert_eq!(
+            error.to_string(),
+            "error: Expected 6 lines describing a monkey\n --> <input>:5:1\n  |\n5 | \n  | ^"
+        );
+    }
+
+    #[test]
+    fn widens_the_gutter_and_names_the_path() {
+        let error = AocError::line(9, "abc", "Expected a number, found `abc`")
+            .with_path(Path::new("2022/day1/data.txt"))
+            .with_path(Path::new("other.txt"));
+        assert_eq!(
+            error.to_string(),
+            "error: Expected a number, found `abc`\n  --> 2022/day1/data.txt:10:1\n   |\n10 | abc\n   | ^^^"
+        );
+    }
+}
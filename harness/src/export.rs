@@ -0,0 +1,28 @@
+use std::{fs, io, path::Path};
+
+mod asciicast;
+pub mod font;
+mod gif;
+
+pub use asciicast::encode_asciicast;
+pub use gif::{encode_gif, GifOptions};
+
+/// Directory that `record_function` writes `<name>.cast` and `<name>.gif` to.
+pub const EXPORT_VAR: &str = "AOC_EXPORT";
+
+pub fn write_asciicast(path: &Path, frames: &[String], fps: f64) -> io::Result<()> {
+    let title = path.file_stem().and_then(|s| s.to_str()).unwrap_or("recording");
+    fs::write(path, encode_asciicast(frames, fps, title))
+}
+
+pub fn write_gif(path: &Path, frames: &[String], options: &GifOptions) -> io::Result<()> {
+    fs::write(path, encode_gif(frames, options)?)
+}
+
+/// Widest line and most lines over all frames, in characters.
+fn frame_size(frames: &[String]) -> (usize, usize) {
+    frames.iter().fold((0, 0), |(width, height), frame| {
+        let w = frame.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        (width.max(w), height.max(frame.lines().count()))
+    })
+}
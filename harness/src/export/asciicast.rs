@@ -0,0 +1,46 @@
+use std::fmt::Write;
+
+use super::frame_size;
+use crate::json::json_string;
+
+/// Encodes frames as an asciicast v2 recording, one frame every `1 / fps`
+/// seconds. Each frame clears the screen before it is drawn.
+pub fn encode_asciicast(frames: &[String], fps: f64, title: &str) -> String {
+    let (width, height) = frame_size(frames);
+    let mut cast = String::new();
+    writeln!(
+        cast,
+        "{{\"version\": 2, \"width\": {}, \"height\": {}, \"title\": {}, \"env\": {{\"TERM\": \"xterm-256color\"}}}}",
+        width.max(1),
+        height.max(1) + 1,
+        json_string(title),
+    )
+    .unwrap();
+
+    let interval = 1.0 / fps.max(0.1);
+    for (i, frame) in frames.iter().enumerate() {
+        let data = format!("\x1b[2J\x1b[H{}", frame.replace('\n', "\r\n"));
+        writeln!(cast, "[{:.6}, \"o\", {}]", i as f64 * interval, json_string(&data)).unwrap();
+    }
+    cast
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_the_header_and_an_escaped_event_per_frame() {
+        let frames = [String::from("a\"b\\c\n\td\x07\x1b"), String::from("xyz")];
+        let cast = encode_asciicast(&frames, 4.0, "Day \"1\"");
+        let lines: Vec<&str> = cast.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                r#"{"version": 2, "width": 5, "height": 3, "title": "Day \"1\"", "env": {"TERM": "xterm-256color"}}"#,
+                r#"[0.000000, "o", "\u001b[2J\u001b[Ha\"b\\c\r\n\td\u0007\u001b"]"#,
+                r#"[0.250000, "o", "\u001b[2J\u001b[Hxyz"]"#,
+            ]
+        );
+    }
+}
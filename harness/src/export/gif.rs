@@ -0,0 +1,299 @@
+use std::{collections::HashMap, io};
+
+use super::{
+    font::{self, GLYPH_HEIGHT, GLYPH_WIDTH},
+    frame_size,
+};
+
+const CELL_WIDTH: usize = GLYPH_WIDTH + 1;
+const CELL_HEIGHT: usize = GLYPH_HEIGHT + 2;
+const MIN_CODE_SIZE: u8 = 2;
+const MAX_CODE: u16 = 4096;
+
+#[derive(Clone, Debug)]
+pub struct GifOptions {
+    pub fps: f64,
+    /// Pixels per font pixel.
+    pub scale: usize,
+    pub foreground: [u8; 3],
+    pub background: [u8; 3],
+    /// Loop forever instead of stopping on the last frame.
+    pub repeat: bool,
+}
+
+impl Default for GifOptions {
+    fn default() -> GifOptions {
+        GifOptions {
+            fps: 10.0,
+            scale: 2,
+            foreground: [0xcc, 0xcc, 0xcc],
+            background: [0x0f, 0x0f, 0x23],
+            repeat: true,
+        }
+    }
+}
+
+/// Renders every frame with the built-in pixel font and encodes them as an
+/// animated GIF.
+pub fn encode_gif(frames: &[String], options: &GifOptions) -> io::Result<Vec<u8>> {
+    let (cols, rows) = frame_size(frames);
+    let scale = options.scale.max(1);
+    let width = cols.max(1) * CELL_WIDTH * scale;
+    let height = rows.max(1) * CELL_HEIGHT * scale;
+    if width > u16::MAX as usize || height > u16::MAX as usize {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("frames render to {}x{} pixels, larger than a GIF allows", width, height),
+        ));
+    }
+
+    let mut out = Vec::new();
+    out.extend_from_slice(b"GIF89a");
+    push_u16(&mut out, width as u16);
+    push_u16(&mut out, height as u16);
+    // global color table of 4 entries, 8 bits per channel
+    out.extend_from_slice(&[0b1111_0001, 0, 0]);
+    out.extend_from_slice(&options.background);
+    out.extend_from_slice(&options.foreground);
+    out.extend_from_slice(&options.background);
+    out.extend_from_slice(&options.background);
+
+    if options.repeat {
+        out.extend_from_slice(&[0x21, 0xff, 0x0b]);
+        out.extend_from_slice(b"NETSCAPE2.0");
+        out.extend_from_slice(&[0x03, 0x01, 0x00, 0x00, 0x00]);
+    }
+
+    let delay = (100.0 / options.fps.max(0.1)).round().clamp(2.0, u16::MAX as f64) as u16;
+    for frame in frames {
+        out.extend_from_slice(&[0x21, 0xf9, 0x04, 0x00]);
+        push_u16(&mut out, delay);
+        out.extend_from_slice(&[0x00, 0x00]);
+
+        out.push(0x2c);
+        push_u16(&mut out, 0);
+        push_u16(&mut out, 0);
+        push_u16(&mut out, width as u16);
+        push_u16(&mut out, height as u16);
+        out.push(0);
+
+        let pixels = rasterize(frame, width, height, scale);
+        out.push(MIN_CODE_SIZE);
+        for block in lzw_encode(&pixels).chunks(255) {
+            out.push(block.len() as u8);
+            out.extend_from_slice(block);
+        }
+        out.push(0);
+    }
+
+    out.push(0x3b);
+    Ok(out)
+}
+
+fn push_u16(out: &mut Vec<u8>, v: u16) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+fn rasterize(frame: &str, width: usize, height: usize, scale: usize) -> Vec<u8> {
+    let mut pixels = vec![0; width * height];
+    for (row, line) in frame.lines().enumerate() {
+        for (col, c) in line.chars().enumerate() {
+            let glyph = font::glyph(c);
+            for gy in 0..GLYPH_HEIGHT {
+                for gx in 0..GLYPH_WIDTH {
+                    if !font::is_set(glyph, gx, gy) {
+                        continue;
+                    }
+                    let px = (col * CELL_WIDTH + gx) * scale;
+                    let py = (row * CELL_HEIGHT + 1 + gy) * scale;
+                    for dy in 0..scale {
+                        let start = (py + dy) * width + px;
+                        pixels[start..start + scale].fill(1);
+                    }
+                }
+            }
+        }
+    }
+    pixels
+}
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    acc: u32,
+    bits: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u8) {
+        self.acc |= (code as u32) << self.bits;
+        self.bits += size;
+        while self.bits >= 8 {
+            self.bytes.push(self.acc as u8);
+            self.acc >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.acc as u8);
+        }
+        self.bytes
+    }
+}
+
+fn lzw_encode(pixels: &[u8]) -> Vec<u8> {
+    let clear = 1u16 << MIN_CODE_SIZE;
+    let end = clear + 1;
+    let mut writer = BitWriter { bytes: Vec::new(), acc: 0, bits: 0 };
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut code_size = MIN_CODE_SIZE + 1;
+    let mut next_code = end + 1;
+
+    writer.write(clear, code_size);
+    let mut prefix: Option<u16> = None;
+    for &pixel in pixels {
+        let Some(p) = prefix else {
+            prefix = Some(pixel as u16);
+            continue;
+        };
+        if let Some(&code) = table.get(&(p, pixel)) {
+            prefix = Some(code);
+            continue;
+        }
+
+        writer.write(p, code_size);
+        if next_code < MAX_CODE {
+            table.insert((p, pixel), next_code);
+            next_code += 1;
+            if next_code > 1 << code_size {
+                code_size += 1;
+            }
+        } else {
+            writer.write(clear, code_size);
+            table.clear();
+            code_size = MIN_CODE_SIZE + 1;
+            next_code = end + 1;
+        }
+        prefix = Some(pixel as u16);
+    }
+
+    if let Some(p) = prefix {
+        writer.write(p, code_size);
+        // the decoder adds one more entry after reading the last code
+        if next_code >= 1 << code_size && code_size < 12 {
+            code_size += 1;
+        }
+    }
+    writer.write(end, code_size);
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reads LZW codes back into pixels the way a GIF decoder does.
+    fn lzw_decode(data: &[u8]) -> Vec<u8> {
+        let clear = 1usize << MIN_CODE_SIZE;
+        let end = clear + 1;
+        let reset = || (0..clear as u8).map(|i| vec![i]).chain([vec![], vec![]]).collect::<Vec<Vec<u8>>>();
+        let mut table = reset();
+        let mut code_size = MIN_CODE_SIZE + 1;
+        let mut prev: Option<Vec<u8>> = None;
+        let mut pixels = Vec::new();
+        let (mut acc, mut bits, mut bytes) = (0u32, 0u8, data.iter());
+        loop {
+            while bits < code_size {
+                acc |= (*bytes.next().expect("data ended before the end code") as u32) << bits;
+                bits += 8;
+            }
+            let code = (acc & ((1 << code_size) - 1)) as usize;
+            acc >>= code_size;
+            bits -= code_size;
+
+            if code == clear {
+                table = reset();
+                code_size = MIN_CODE_SIZE + 1;
+                prev = None;
+                continue;
+            }
+            if code == end {
+                return pixels;
+            }
+            let entry = match (table.get(code), &prev) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(p)) => [p.as_slice(), &p[..1]].concat(),
+                (None, None) => panic!("code {} before any pixels", code),
+            };
+            if let Some(p) = prev {
+                if table.len() < MAX_CODE as usize {
+                    table.push([p.as_slice(), &entry[..1]].concat());
+                }
+            }
+            pixels.extend_from_slice(&entry);
+            if table.len() == 1 << code_size && code_size < 12 {
+                code_size += 1;
+            }
+            prev = Some(entry);
+        }
+    }
+
+    #[test]
+    fn decodes_back_to_the_pixels() {
+        let mut seed = 7u32;
+        let noise: Vec<u8> = (0..100_000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                (seed >> 16) as u8 % 2
+            })
+            .collect();
+        for pixels in [vec![], vec![1], vec![0; 5000], noise] {
+            assert_eq!(lzw_decode(&lzw_encode(&pixels)), pixels);
+        }
+    }
+
+    #[test]
+    fn writes_a_gif_of_the_frames() {
+        let frames = [String::from("#.\n.#\n"), String::from("..\n##\n")];
+        let options = GifOptions { scale: 3, ..GifOptions::default() };
+        let gif = encode_gif(&frames, &options).unwrap();
+        let (width, height) = (2 * CELL_WIDTH * 3, 2 * CELL_HEIGHT * 3);
+
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(&gif[6..10], [(width as u16).to_le_bytes(), (height as u16).to_le_bytes()].concat());
+        assert_eq!(gif[10], 0b1111_0001);
+        assert_eq!(gif.last(), Some(&0x3b));
+
+        // Skip the color table and extensions to each image's data.
+        let mut i = 13 + 4 * 3;
+        let mut images = Vec::new();
+        while gif[i] != 0x3b {
+            match gif[i] {
+                0x21 => {
+                    i += 2;
+                    while gif[i] != 0 {
+                        i += gif[i] as usize + 1;
+                    }
+                    i += 1;
+                }
+                0x2c => {
+                    i += 10;
+                    assert_eq!(gif[i], MIN_CODE_SIZE);
+                    i += 1;
+                    let mut data = Vec::new();
+                    while gif[i] != 0 {
+                        data.extend_from_slice(&gif[i + 1..i + 1 + gif[i] as usize]);
+                        i += gif[i] as usize + 1;
+                    }
+                    i += 1;
+                    images.push(lzw_decode(&data));
+                }
+                b => panic!("unexpected block {:#x} at {}", b, i),
+            }
+        }
+        assert_eq!(i, gif.len() - 1);
+        let expected: Vec<Vec<u8>> = frames.iter().map(|f| rasterize(f, width, height, 3)).collect();
+        assert_eq!(images, expected);
+    }
+}
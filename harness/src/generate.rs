@@ -0,0 +1,90 @@
+use std::ops::RangeInclusive;
+
+/// Small seeded PRNG (SplitMix64) for input generators, so a seed always
+/// reproduces the same input without pulling in a crate.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Empty range {}..={}", start, end);
+        let span = (end - start) as u64 + 1;
+        start + (self.next_u64() % span) as i64
+    }
+
+    /// Uniform index below `n`, which must be positive.
+    pub fn below(&mut self, n: usize) -> usize {
+        self.range(0..=n as i64 - 1) as usize
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    /// A word of `len` letters drawn from `alphabet`.
+    pub fn word(&mut self, alphabet: &str, len: usize) -> String {
+        let letters: Vec<char> = alphabet.chars().collect();
+        (0..len).map(|_| *self.pick(&letters)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draws(seed: u64) -> (Vec<u64>, Vec<i64>, Vec<bool>, Vec<u32>, String) {
+        let mut rng = Rng::new(seed);
+        let numbers = (0..8).map(|_| rng.next_u64()).collect();
+        let ranged = (0..50).map(|_| rng.range(-3..=3)).collect();
+        let chances = (0..8).map(|_| rng.chance(0.5)).collect();
+        let mut shuffled: Vec<u32> = (0..10).collect();
+        rng.shuffle(&mut shuffled);
+        (numbers, ranged, chances, shuffled, rng.word("abc", 12))
+    }
+
+    #[test]
+    fn repeats_itself_for_a_seed() {
+        assert_eq!(draws(42), draws(42));
+        assert_ne!(draws(42).0, draws(43).0);
+        // SplitMix64's first output for a zero seed.
+        assert_eq!(Rng::new(0).next_u64(), 0xe220a8397b1dcdaf);
+    }
+
+    #[test]
+    fn stays_in_bounds() {
+        let (_, ranged, _, mut shuffled, word) = draws(7);
+        assert!(ranged.iter().all(|n| (-3..=3).contains(n)));
+        assert!((-3..=3).all(|n| ranged.contains(&n)));
+        shuffled.sort();
+        assert_eq!(shuffled, (0..10).collect::<Vec<_>>());
+        assert!(word.len() == 12 && word.chars().all(|c| "abc".contains(c)));
+        let mut rng = Rng::new(7);
+        assert!((0..100).all(|_| rng.below(1) == 0 && !rng.chance(0.0) && rng.chance(1.0)));
+    }
+}
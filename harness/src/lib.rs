@@ -1,4 +1,19 @@
-use std::{path::Path, fs::File, io, io::{BufReader, Read}, time::{Instant}, str::Lines};
+use std::{fmt, path::Path, fs::File, io, io::{BufReader, Read}, process, time::{Instant}, str::Lines};
+
+pub mod bench;
+pub mod error;
+pub mod export;
+pub mod generate;
+pub mod json;
+pub mod recorder;
+pub mod repl;
+pub mod snapshot;
+pub mod solution;
+pub mod testing;
+
+pub use error::AocError;
+pub use recorder::FrameRecorder;
+pub use solution::Solution;
 
 pub fn get_input(path: &Path) -> io::Result<String> {
     let file = File::open(path)?;
@@ -8,6 +23,34 @@ pub fn get_input(path: &Path) -> io::Result<String> {
     Ok(buff)
 }
 
+/// Reads the file at `path`, or all of stdin without one.
+pub fn read_input(path: Option<&Path>) -> io::Result<String> {
+    match path {
+        Some(path) => get_input(path),
+        None => {
+            let mut buff = String::new();
+            io::stdin().read_to_string(&mut buff)?;
+            Ok(buff)
+        }
+    }
+}
+
+/// Reads and parses the input of a day binary's extra mode with
+/// `read_input`, exiting with the error when either fails.
+pub fn parse_input<T>(path: Option<&Path>, parse: impl FnOnce(&str) -> Result<T, AocError>) -> T {
+    let input = read_input(path).unwrap_or_else(|e| fail(format!("Error reading input: {}", e)));
+    parse(&input).unwrap_or_else(|e| match path {
+        Some(path) => fail(e.with_path(path)),
+        None => fail(e),
+    })
+}
+
+/// Prints `message` to stderr and exits with an error.
+pub fn fail(message: impl fmt::Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
 pub fn time_function<T: std::fmt::Display>(path: &str, f: &dyn Fn(&mut Lines) -> T) {
     let str = match get_input(Path::new(path)) {
         Ok(s) => s,
@@ -18,4 +61,35 @@ pub fn time_function<T: std::fmt::Display>(path: &str, f: &dyn Fn(&mut Lines) ->
     let result = f(&mut lines);
     let elapsed_time = start_time.elapsed();
     println!("\nResult:\t\t{}\nDuration:\t{:?}", result, elapsed_time);
-}
\ No newline at end of file
+}
+
+pub fn record_function<T: solution::Answer>(name: &str, path: &str, f: &dyn Fn(&mut Lines, &mut FrameRecorder) -> T) {
+    let mut recorder = FrameRecorder::from_env();
+    if !recorder.is_enabled() {
+        return;
+    }
+
+    let str = match get_input(Path::new(path)) {
+        Ok(s) => s,
+        Err(_) => panic!("Error getting input"),
+    };
+    let result = match f(&mut str.lines(), &mut recorder).into_answer() {
+        Ok(result) => result,
+        Err(e) => {
+            println!("\n{}", e.with_path(Path::new(path)));
+            return;
+        }
+    };
+    let fps = recorder::fps_from_env();
+    if recorder::playback_requested() {
+        if let Err(e) = recorder.play(fps) {
+            panic!("Error playing recording: {}", e);
+        }
+    }
+    if let Ok(dir) = std::env::var(export::EXPORT_VAR) {
+        if let Err(e) = recorder.export(Path::new(&dir), name, fps) {
+            panic!("Error exporting recording: {}", e);
+        }
+    }
+    println!("\nResult:\t\t{}\nFrames:\t\t{}", result, recorder.len());
+}
@@ -0,0 +1,310 @@
+use std::{
+    env, fs,
+    io::{self, Read, Write},
+    path::Path,
+    process::{Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use crate::export::{self, GifOptions};
+
+/// Set to anything but `0` to record frames and play them back.
+pub const RECORD_VAR: &str = "AOC_RECORD";
+/// Playback speed used by `record_function`, in frames per second.
+pub const FPS_VAR: &str = "AOC_FPS";
+pub const DEFAULT_FPS: f64 = 30.0;
+
+/// Collects rendered frames of a simulation.
+///
+/// A disabled recorder never calls the render closure, so solvers can record
+/// unconditionally without slowing down timed runs.
+#[derive(Debug, Default)]
+pub struct FrameRecorder {
+    enabled: bool,
+    frames: Vec<String>,
+}
+
+impl FrameRecorder {
+    pub fn new() -> FrameRecorder {
+        FrameRecorder::default()
+    }
+
+    pub fn enabled() -> FrameRecorder {
+        FrameRecorder { enabled: true, frames: Vec::new() }
+    }
+
+    /// Enabled when playback or exporting was asked for through the environment.
+    pub fn from_env() -> FrameRecorder {
+        if playback_requested() || env::var_os(export::EXPORT_VAR).is_some() {
+            FrameRecorder::enabled()
+        } else {
+            FrameRecorder::new()
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn record<F: FnOnce() -> String>(&mut self, render: F) {
+        if self.enabled {
+            self.frames.push(render());
+        }
+    }
+
+    pub fn push(&mut self, frame: String) {
+        self.record(|| frame);
+    }
+
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn play(&self, fps: f64) -> io::Result<()> {
+        Playback::new(fps).run(&self.frames)
+    }
+
+    /// Writes `<name>.cast` and `<name>.gif` into `dir`.
+    pub fn export(&self, dir: &Path, name: &str, fps: f64) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+        export::write_asciicast(&dir.join(format!("{}.cast", name)), &self.frames, fps)?;
+        let options = GifOptions { fps, ..GifOptions::default() };
+        export::write_gif(&dir.join(format!("{}.gif", name)), &self.frames, &options)
+    }
+}
+
+enum Control {
+    TogglePause,
+    Forward,
+    Back,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Control {
+    fn from_byte(b: u8) -> Option<Control> {
+        match b {
+            b' ' | b'\n' => Some(Control::TogglePause),
+            b'n' | b'.' | b'l' => Some(Control::Forward),
+            b'p' | b',' | b'h' => Some(Control::Back),
+            b'+' | b'=' => Some(Control::Faster),
+            b'-' | b'_' => Some(Control::Slower),
+            b'q' | 0x1b => Some(Control::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Terminal playback of recorded frames.
+///
+/// Keys: `space` pause/resume, `n`/`.` step forward, `p`/`,` step back,
+/// `+`/`-` change speed, `q` quit. Without a terminal the frames just play
+/// through once.
+pub struct Playback {
+    fps: f64,
+    paused: bool,
+}
+
+impl Playback {
+    pub fn new(fps: f64) -> Playback {
+        Playback { fps: fps.max(0.1), paused: false }
+    }
+
+    pub fn paused(mut self, paused: bool) -> Playback {
+        self.paused = paused;
+        self
+    }
+
+    pub fn run(mut self, frames: &[String]) -> io::Result<()> {
+        if frames.is_empty() {
+            return Ok(());
+        }
+
+        let terminal = RawTerminal::enter();
+        let keys = spawn_key_reader();
+        let mut stdout = io::stdout();
+        let mut index = 0;
+        let mut interactive = true;
+
+        loop {
+            self.draw(&mut stdout, frames, index)?;
+
+            let control = if self.paused && interactive {
+                match keys.recv() {
+                    Ok(c) => Some(c),
+                    Err(_) => {
+                        interactive = false;
+                        None
+                    }
+                }
+            } else if interactive {
+                match keys.recv_timeout(self.frame_duration()) {
+                    Ok(c) => Some(c),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        interactive = false;
+                        None
+                    }
+                }
+            } else {
+                thread::sleep(self.frame_duration());
+                None
+            };
+
+            match control {
+                Some(Control::TogglePause) => self.paused = !self.paused,
+                Some(Control::Forward) => {
+                    self.paused = true;
+                    index = (index + 1).min(frames.len() - 1);
+                }
+                Some(Control::Back) => {
+                    self.paused = true;
+                    index = index.saturating_sub(1);
+                }
+                Some(Control::Faster) => self.fps *= 2.0,
+                Some(Control::Slower) => self.fps = (self.fps / 2.0).max(0.1),
+                Some(Control::Quit) => break,
+                None if self.paused && interactive => {}
+                None if index + 1 < frames.len() => index += 1,
+                None if interactive => self.paused = true,
+                None => break,
+            }
+        }
+
+        drop(terminal);
+        writeln!(stdout)?;
+        Ok(())
+    }
+
+    fn frame_duration(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps)
+    }
+
+    fn draw(&self, out: &mut impl Write, frames: &[String], index: usize) -> io::Result<()> {
+        let state = if self.paused { "paused" } else { "playing" };
+        write!(out, "\x1b[2J\x1b[H{}", frames[index])?;
+        if !frames[index].ends_with('\n') {
+            writeln!(out)?;
+        }
+        writeln!(
+            out,
+            "frame {}/{} | {:.1} fps | {} | space: pause  n/p: step  +/-: speed  q: quit",
+            index + 1,
+            frames.len(),
+            self.fps,
+            state,
+        )?;
+        out.flush()
+    }
+}
+
+fn spawn_key_reader() -> Receiver<Control> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut buf = [0; 16];
+        while let Ok(n @ 1..) = io::stdin().read(&mut buf) {
+            for control in buf[..n].iter().filter_map(|b| Control::from_byte(*b)) {
+                if tx.send(control).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+    rx
+}
+
+/// Puts the terminal into unbuffered, no-echo mode for single key presses and
+/// restores the previous settings when dropped.
+struct RawTerminal {
+    saved: Option<String>,
+}
+
+impl RawTerminal {
+    fn enter() -> RawTerminal {
+        let saved = stty(&["-g"]).filter(|_| stty(&["-icanon", "-echo", "min", "1"]).is_some());
+        RawTerminal { saved }
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        if let Some(saved) = &self.saved {
+            stty(&[saved.trim()]);
+        }
+    }
+}
+
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if output.status.success() {
+        String::from_utf8(output.stdout).ok()
+    } else {
+        None
+    }
+}
+
+pub fn playback_requested() -> bool {
+    matches!(env::var(RECORD_VAR), Ok(v) if !v.is_empty() && v != "0")
+}
+
+pub fn fps_from_env() -> f64 {
+    parse_fps(env::var(FPS_VAR).ok().as_deref())
+}
+
+/// A positive frame rate, or `DEFAULT_FPS` if `value` is missing or not one.
+fn parse_fps(value: Option<&str>) -> f64 {
+    value
+        .and_then(|v| v.trim().parse().ok())
+        .filter(|fps: &f64| *fps > 0.0 && fps.is_finite())
+        .unwrap_or(DEFAULT_FPS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_disabled_recorder_never_renders() {
+        let mut recorder = FrameRecorder::new();
+        assert!(!recorder.is_enabled());
+        recorder.record(|| panic!("rendered a frame while disabled"));
+        recorder.push(String::from("frame"));
+        assert!(recorder.is_empty());
+    }
+
+    #[test]
+    fn an_enabled_recorder_keeps_every_frame_in_order() {
+        let mut recorder = FrameRecorder::enabled();
+        for i in 0..3 {
+            recorder.record(|| format!("frame {}", i));
+        }
+        recorder.push(String::from("last"));
+        assert_eq!(recorder.len(), 4);
+        assert_eq!(recorder.frames(), ["frame 0", "frame 1", "frame 2", "last"]);
+    }
+
+    #[test]
+    fn reads_a_positive_frame_rate() {
+        assert_eq!(parse_fps(Some("12")), 12.0);
+        assert_eq!(parse_fps(Some(" 2.5 ")), 2.5);
+        for value in [None, Some(""), Some("fast"), Some("0"), Some("-5"), Some("inf"), Some("NaN")] {
+            assert_eq!(parse_fps(value), DEFAULT_FPS, "{:?}", value);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    fmt::Debug,
+    io::{self, BufRead, Write},
+};
+
+type Command<S> = Box<dyn Fn(&mut S, &[&str]) -> Result<String, String>>;
+
+/// Line-based REPL over a day's parsed state.
+///
+/// `state` prints the state with its `Debug` impl, `help` lists every command
+/// and `quit` leaves. Everything else is registered by the day with `command`.
+/// An empty line repeats the last command, so stepping is one key press.
+pub struct Repl<S> {
+    name: String,
+    state: S,
+    commands: Vec<(&'static str, &'static str, Command<S>)>,
+}
+
+impl<S: Debug> Repl<S> {
+    pub fn new(name: &str, state: S) -> Repl<S> {
+        Repl { name: name.to_string(), state, commands: Vec::new() }
+    }
+
+    pub fn command(
+        mut self,
+        name: &'static str,
+        help: &'static str,
+        f: impl Fn(&mut S, &[&str]) -> Result<String, String> + 'static,
+    ) -> Repl<S> {
+        self.commands.push((name, help, Box::new(f)));
+        self
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Runs one command line. Returns `None` once the user asks to quit.
+    pub fn execute(&mut self, line: &str) -> Option<Result<String, String>> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let (&name, args) = words.split_first()?;
+        Some(match name {
+            "quit" | "exit" | "q" => return None,
+            "help" | "?" => Ok(self.help()),
+            "state" => Ok(format!("{:#?}", self.state)),
+            _ => match self.commands.iter().find(|(n, _, _)| *n == name) {
+                Some((_, _, f)) => f(&mut self.state, args),
+                None => Err(format!("Unknown command `{}`, try `help`", name)),
+            },
+        })
+    }
+
+    pub fn run(&mut self) -> io::Result<()> {
+        self.run_with(&mut io::stdin().lock(), &mut io::stdout())
+    }
+
+    pub fn run_with(&mut self, input: &mut impl BufRead, output: &mut impl Write) -> io::Result<()> {
+        writeln!(output, "{}", self.help())?;
+        let mut last = String::from("help");
+        loop {
+            write!(output, "{}> ", self.name)?;
+            output.flush()?;
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                return Ok(());
+            }
+            if line.trim().is_empty() {
+                line = last.clone();
+            }
+            match self.execute(&line) {
+                Some(Ok(s)) => writeln!(output, "{}", s)?,
+                Some(Err(e)) => writeln!(output, "Error: {}", e)?,
+                None => return Ok(()),
+            }
+            last = line;
+        }
+    }
+
+    fn help(&self) -> String {
+        let mut s = String::from("Commands:");
+        for (name, help, _) in &self.commands {
+            s.push_str(&format!("\n    {:<12}{}", name, help));
+        }
+        s.push_str("\n    state       print the parsed state\n    help        list commands\n    quit        leave");
+        s
+    }
+}
+
+/// Parses an optional repeat count, e.g. `step 10`, defaulting to 1.
+pub fn count(args: &[&str]) -> Result<usize, String> {
+    match args.first() {
+        Some(n) => n.parse().map_err(|_| format!("Invalid count: {}", n)),
+        None => Ok(1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn counter() -> Repl<u32> {
+        Repl::new("count", 0).command("inc", "[n] add n", |total, args| {
+            *total += count(args)? as u32;
+            Ok(total.to_string())
+        })
+    }
+
+    fn run(repl: &mut Repl<u32>, script: &str) -> String {
+        let mut output = Vec::new();
+        repl.run_with(&mut Cursor::new(script), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn runs_a_session_until_end_of_input() {
+        let mut repl = counter();
+        let output = run(&mut repl, "inc\n\ninc 3\ninc x\nbogus\nstate\n");
+        assert_eq!(
+            output,
+            concat!(
+                "Commands:\n",
+                "    inc         [n] add n\n",
+                "    state       print the parsed state\n",
+                "    help        list commands\n",
+                "    quit        leave\n",
+                "count> 1\n",
+                "count> 2\n",
+                "count> 5\n",
+                "count> Error: Invalid count: x\n",
+                "count> Error: Unknown command `bogus`, try `help`\n",
+                "count> 5\n",
+                "count> ",
+            )
+        );
+        assert_eq!(*repl.state(), 5);
+    }
+
+    #[test]
+    fn stops_at_quit() {
+        let mut repl = counter();
+        assert!(run(&mut repl, "inc 2\nquit\ninc\n").ends_with("count> 2\ncount> "));
+        assert_eq!(*repl.state(), 2);
+        assert_eq!(repl.execute("exit"), None);
+        assert_eq!(repl.execute("help").unwrap().unwrap().lines().next(), Some("Commands:"));
+    }
+}
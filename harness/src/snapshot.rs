@@ -0,0 +1,129 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+pub const SNAPSHOT_DIR: &str = "snapshots";
+/// Output that did not match its snapshot waits here until accepted.
+pub const PENDING_EXTENSION: &str = "snap.new";
+
+/// Where the snapshot `name` of the crate in `dir` is kept.
+pub fn path(dir: &str, name: &str) -> PathBuf {
+    Path::new(dir).join(SNAPSHOT_DIR).join(format!("{}.snap", name))
+}
+
+/// Checks `actual` against the snapshot `name` of the crate in `dir`.
+///
+/// A missing or different snapshot fails with a diff, leaving `actual` in a
+/// `.snap.new` file next to it for `aoc snapshots --accept`. With
+/// `AOC_UPDATE_SNAPSHOTS=1` the snapshot is overwritten instead.
+pub fn assert_snapshot(dir: &str, name: &str, actual: &str) {
+    let path = path(dir, name);
+    let pending = path.with_extension(PENDING_EXTENSION);
+    let expected = fs::read_to_string(&path).ok();
+    if expected.as_deref() == Some(actual) {
+        let _ = fs::remove_file(&pending);
+        return;
+    }
+
+    let update = env::var("AOC_UPDATE_SNAPSHOTS").is_ok_and(|v| v == "1");
+    let target = if update { &path } else { &pending };
+    if let Err(e) = fs::create_dir_all(path.parent().unwrap()).and_then(|_| fs::write(target, actual)) {
+        panic!("Error writing {}: {}", target.display(), e);
+    }
+    if update {
+        return;
+    }
+    match expected {
+        Some(expected) => panic!(
+            "Snapshot {} does not match {}\n{}\nRun `aoc snapshots --accept` to accept the new output.",
+            name,
+            path.display(),
+            diff(&expected, actual),
+        ),
+        None => panic!(
+            "No snapshot {} yet, the output was saved to {}\nRun `aoc snapshots --accept` to accept it.",
+            name,
+            pending.display(),
+        ),
+    }
+}
+
+/// Line by line diff, marking lines only in `expected` with `-` and lines
+/// only in `actual` with `+`.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.split('\n').collect();
+    let new: Vec<&str> = actual.split('\n').collect();
+
+    // Longest common subsequence lengths of every pair of suffixes.
+    let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = match old[i] == new[j] {
+                true => lcs[i + 1][j + 1] + 1,
+                false => lcs[i + 1][j].max(lcs[i][j + 1]),
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(format!("  {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    lines.join("\n")
+}
+
+/// Snapshots of the crate in `dir` waiting to be accepted.
+pub fn pending(dir: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir.join(SNAPSHOT_DIR))
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| p.to_str().is_some_and(|p| p.ends_with(PENDING_EXTENSION)))
+                .collect()
+        })
+        .unwrap_or_default();
+    paths.sort();
+    paths
+}
+
+/// Replaces a snapshot with its pending output, returning the snapshot's path.
+pub fn accept(pending: &Path) -> io::Result<PathBuf> {
+    let path = pending.with_extension("").with_extension("snap");
+    fs::rename(pending, &path)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diffs_equal_text_as_unchanged() {
+        assert_eq!(diff("a\nb\nc", "a\nb\nc"), "  a\n  b\n  c");
+    }
+
+    #[test]
+    fn diffs_added_and_removed_lines() {
+        assert_eq!(diff("a\nc", "a\nb\nc\nd"), "  a\n+ b\n  c\n+ d");
+        assert_eq!(diff("a\nb\nc\nd", "b\nc"), "- a\n  b\n  c\n- d");
+    }
+
+    #[test]
+    fn diffs_a_changed_line_as_removed_then_added() {
+        assert_eq!(diff("a\nb\nc", "a\nB\nc"), "  a\n- b\n+ B\n  c");
+        assert_eq!(diff("#.\n..", "#.\n.#"), "  #.\n- ..\n+ .#");
+    }
+}
@@ -0,0 +1,306 @@
+use std::{
+    collections::HashMap,
+    env, fs, io, panic,
+    path::{Path, PathBuf},
+    str::Lines,
+    time::{Duration, Instant},
+};
+
+use crate::{generate::Rng, get_input, AocError};
+
+pub const INPUTS: [&str; 2] = ["example", "data"];
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// What a part can return: a printable answer, or a `Result` of one when the
+/// input can fail to parse.
+pub trait Answer {
+    fn into_answer(self) -> Result<String, AocError>;
+}
+
+macro_rules! display_answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn into_answer(self) -> Result<String, AocError> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+display_answer!(i32, i64, i128, u32, u64, u128, usize, String, &str);
+
+impl<T: Answer> Answer for Result<T, AocError> {
+    fn into_answer(self) -> Result<String, AocError> {
+        self.and_then(Answer::into_answer)
+    }
+}
+
+/// What a day's puzzle is about, for listing and grouping days.
+#[derive(Clone, Copy, Debug)]
+pub struct Metadata {
+    pub title: &'static str,
+    /// Broad categories, like `grid`, `simulation`, `parsing` or `graph`.
+    pub tags: &'static [&'static str],
+    pub algorithms: &'static [&'static str],
+    /// From 1 (warm-up) to 5 (took all evening), or 0 when not rated yet.
+    pub difficulty: u8,
+}
+
+impl Metadata {
+    /// For days that have not described themselves yet.
+    pub const UNKNOWN: Metadata = Metadata { title: "", tags: &[], algorithms: &[], difficulty: 0 };
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+}
+
+/// A day's parts with their answers turned into strings, so every day can be
+/// run the same way whatever its parts return.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub metadata: Metadata,
+    /// Directory holding the day's inputs and answers.
+    pub dir: &'static str,
+    pub part1: fn(&mut Lines) -> Result<String, AocError>,
+    pub part2: fn(&mut Lines) -> Result<String, AocError>,
+    /// Interactive REPL over the parsed input, for days that have one.
+    pub repl: Option<fn(&mut Lines) -> io::Result<()>>,
+    /// Random input generator taking a size, for stress-testing.
+    pub generate: Option<fn(&mut Rng, usize) -> String>,
+}
+
+/// Declares `pub const SOLUTION` for the calling day crate.
+#[macro_export]
+macro_rules! solution {
+    (
+        year: $year:expr, day: $day:expr, part1: $part1:path, part2: $part2:path
+        $(, title: $title:expr, tags: [$($tag:expr),* $(,)?], algorithms: [$($algorithm:expr),* $(,)?], difficulty: $difficulty:expr)?
+        $(, repl: $repl:path)? $(, generate: $generate:path)? $(,)?
+    ) => {
+        pub const SOLUTION: $crate::Solution = $crate::Solution {
+            year: $year,
+            day: $day,
+            metadata: $crate::solution!(@metadata $($title, [$($tag),*], [$($algorithm),*], $difficulty)?),
+            dir: env!("CARGO_MANIFEST_DIR"),
+            part1: |lines| $crate::solution::Answer::into_answer($part1(lines)),
+            part2: |lines| $crate::solution::Answer::into_answer($part2(lines)),
+            repl: $crate::solution!(@optional $($repl)?),
+            generate: $crate::solution!(@optional $($generate)?),
+        };
+    };
+    (
+        year: $year:expr, day: $day:expr
+        $(, title: $title:expr, tags: [$($tag:expr),* $(,)?], algorithms: [$($algorithm:expr),* $(,)?], difficulty: $difficulty:expr)?
+        $(, repl: $repl:path)? $(, generate: $generate:path)? $(,)?
+    ) => {
+        $crate::solution!(
+            year: $year, day: $day, part1: part1, part2: part2
+            $(, title: $title, tags: [$($tag),*], algorithms: [$($algorithm),*], difficulty: $difficulty)?
+            $(, repl: $repl)? $(, generate: $generate)?
+        );
+    };
+    (@metadata) => { $crate::solution::Metadata::UNKNOWN };
+    (@metadata $title:expr, [$($tag:expr),*], [$($algorithm:expr),*], $difficulty:expr) => {
+        $crate::solution::Metadata { title: $title, tags: &[$($tag),*], algorithms: &[$($algorithm),*], difficulty: $difficulty }
+    };
+    (@optional) => { None };
+    (@optional $f:path) => { Some($f) };
+}
+
+impl Solution {
+    pub fn part(&self, part: u8) -> fn(&mut Lines) -> Result<String, AocError> {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => panic!("{} has no part {}", self.name(), part),
+        }
+    }
+
+    /// `<year>/day<day>`, as the day's directory is laid out.
+    pub fn name(&self) -> String {
+        format!("{}/day{}", self.year, self.day)
+    }
+
+    pub fn input_path(&self, input: &str) -> PathBuf {
+        Path::new(self.dir).join(format!("{}.txt", input))
+    }
+
+    pub fn answers(&self) -> Answers {
+        Answers::load(&Path::new(self.dir).join(ANSWERS_FILE))
+    }
+
+    pub fn solve(&self, part: u8, input: &str) -> (Result<String, AocError>, Duration) {
+        let mut lines = input.lines();
+        let start_time = Instant::now();
+        let result = self.part(part)(&mut lines);
+        (result, start_time.elapsed())
+    }
+
+    /// Like `solve`, but turns a panicking part into an error instead of
+    /// unwinding into the caller.
+    pub fn try_solve(&self, part: u8, input: &str) -> Result<(Result<String, AocError>, Duration), String> {
+        if part != 1 && part != 2 {
+            return Err(format!("{} has no part {}", self.name(), part));
+        }
+        panic::catch_unwind(|| self.solve(part, input)).map_err(|payload| {
+            match payload.downcast::<String>() {
+                Ok(message) => *message,
+                Err(payload) => match payload.downcast::<&str>() {
+                    Ok(message) => message.to_string(),
+                    Err(_) => String::from("Solution panicked"),
+                },
+            }
+        })
+    }
+
+    /// Times both parts over every input and checks them against the answers file.
+    pub fn run(&self) {
+        for part in [1, 2] {
+            for input in INPUTS {
+                print!("{}", self.report(part, input));
+            }
+        }
+    }
+
+    /// Solves one part of one input and describes the result the way `run`
+    /// prints it. A panicking part is reported rather than unwinding, so runs
+    /// on other threads can carry on.
+    pub fn report(&self, part: u8, input: &str) -> String {
+        let path = self.input_path(input);
+        let heading = format!("\nPart {} ({})\n", part, input);
+        let str = match get_input(&path) {
+            Ok(s) => s,
+            Err(e) => return format!("{}Skipped:\t{}: {}\n", heading, path.display(), e),
+        };
+        let (result, elapsed_time) = match self.try_solve(part, &str) {
+            Ok(solved) => solved,
+            Err(message) => return format!("{}Panicked:\t{}\n", heading, message),
+        };
+        let result = match result {
+            Ok(result) => result,
+            Err(e) => return format!("{}{}\n", heading, e.with_path(relative(&path))),
+        };
+        let mut report = format!("{}Result:\t\t{}\nDuration:\t{:?}\n", heading, result, elapsed_time);
+        match self.answers().get(input, part) {
+            Some(answer) if answer == result => report.push_str("Answer:\t\tcorrect\n"),
+            Some(answer) => report.push_str(&format!("Answer:\t\tWRONG, expected {}\n", answer)),
+            None => {}
+        }
+        report
+    }
+}
+
+/// `path` relative to the working directory when it is inside it, as
+/// compilers print them.
+fn relative(path: &Path) -> &Path {
+    match env::current_dir() {
+        Ok(dir) => path.strip_prefix(dir).unwrap_or(path),
+        Err(_) => path,
+    }
+}
+
+/// Known answers, read from lines like `data part1: 1234`. Blank answers are
+/// treated as unknown.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(String, u8), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Answers {
+        fs::read_to_string(path).map(|s| Answers::parse(&s)).unwrap_or_default()
+    }
+
+    pub fn parse(s: &str) -> Answers {
+        let mut answers = HashMap::new();
+        for line in s.lines().filter(|l| !l.trim_start().starts_with('#')) {
+            let Some((key, value)) = line.split_once(':') else { continue };
+            let Some((input, part)) = key.trim().split_once(" part") else { continue };
+            let (Ok(part), value) = (part.parse(), value.trim()) else { continue };
+            if !value.is_empty() {
+                answers.insert((input.to_string(), part), value.to_string());
+            }
+        }
+        Answers { answers }
+    }
+
+    pub fn get(&self, input: &str, part: u8) -> Option<&str> {
+        self.answers.get(&(input.to_string(), part)).map(|s| s.as_str())
+    }
+
+    /// Writes `answer` into the answers file at `path`, filling in its blank
+    /// line for the input and part or adding one. Known answers are kept.
+    pub fn save(path: &Path, input: &str, part: u8, answer: &str) -> io::Result<()> {
+        let contents = fs::read_to_string(path).unwrap_or_else(|_| Answers::template());
+        if Answers::parse(&contents).get(input, part).is_some() {
+            return Ok(());
+        }
+        let key = format!("{} part{}:", input, part);
+        let line = format!("{} {}", key, answer);
+        let mut lines: Vec<&str> = contents.lines().collect();
+        match lines.iter().position(|l| l.trim() == key) {
+            Some(index) => lines[index] = &line,
+            None => lines.push(&line),
+        }
+        fs::write(path, lines.join("\n") + "\n")
+    }
+
+    /// Contents of a fresh answers file with every answer blank.
+    pub fn template() -> String {
+        let mut s = String::from("# Expected answers, left blank until known\n");
+        for input in INPUTS {
+            for part in [1, 2] {
+                s.push_str(&format!("{} part{}:\n", input, part));
+            }
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use super::*;
+
+    #[test]
+    fn matches_tags_ignoring_case() {
+        let metadata = Metadata { title: "Hill Climbing Algorithm", tags: &["grid", "Graph"], algorithms: &[], difficulty: 3 };
+        assert!(metadata.has_tag("grid"));
+        assert!(metadata.has_tag("GRID"));
+        assert!(metadata.has_tag("graph"));
+        assert!(!metadata.has_tag("gri"));
+        assert!(!metadata.has_tag(""));
+        assert!(!Metadata::UNKNOWN.has_tag("grid"));
+    }
+
+    #[test]
+    fn saves_answers_into_their_blank_lines() {
+        let dir = env::temp_dir().join(format!("aoc-answers-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(ANSWERS_FILE);
+        let lines = || fs::read_to_string(&path).unwrap().lines().map(String::from).collect::<Vec<_>>();
+
+        Answers::save(&path, "data", 2, "45000").unwrap();
+        let template = Answers::template();
+        let mut expected: Vec<String> = template.lines().map(String::from).collect();
+        let index = expected.iter().position(|l| l == "data part2:").unwrap();
+        expected[index] = String::from("data part2: 45000");
+        assert_eq!(lines(), expected);
+
+        // Known answers are kept, and inputs missing from the file are added.
+        Answers::save(&path, "data", 2, "1").unwrap();
+        Answers::save(&path, "big", 1, "-12").unwrap();
+        expected.push(String::from("big part1: -12"));
+        assert_eq!(lines(), expected);
+
+        let answers = Answers::load(&path);
+        assert_eq!(answers.get("data", 2), Some("45000"));
+        assert_eq!(answers.get("big", 1), Some("-12"));
+        assert_eq!(answers.get("data", 1), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,83 @@
+/// Generates `#[test]` functions checking the day's parts against example
+/// inputs.
+///
+/// Each example gets a module named after it, with one test per listed part:
+///
+/// ```ignore
+/// harness::example_tests! {
+///     example: "
+/// A Y
+/// B X
+/// C Z" => { part1: 15, part2: 12 },
+/// }
+/// ```
+///
+/// A single leading newline is dropped from the input so it can start on its
+/// own line. The parts are called from the module the macro is used in and
+/// return a `Result`, whose error fails the test with its diagnostic.
+#[macro_export]
+macro_rules! example_tests {
+    ($($name:ident: $input:expr => { $($part:ident: $expected:expr),+ $(,)? }),+ $(,)?) => {
+        #[cfg(test)]
+        pub(crate) mod example_tests {
+            $(
+                pub(crate) mod $name {
+                    pub(crate) const INPUT: &str = $input;
+
+                    $(
+                        #[test]
+                        fn $part() {
+                            let input = $crate::testing::example_input(INPUT);
+                            match super::super::$part(&mut input.lines()) {
+                                Ok(answer) => assert_eq!(answer, $expected),
+                                Err(e) => panic!("\n{}", e),
+                            }
+                        }
+                    )+
+                }
+            )+
+        }
+    };
+}
+
+/// Generates `#[test]` functions snapshotting what simulations draw, for
+/// outputs that are pictures rather than numbers.
+///
+/// Each listed function takes the input and a `FrameRecorder`, like
+/// `simulate_part1`, and is run on an example from `example_tests!`. The last
+/// frame it records is checked against `snapshots/<example>_<function>.snap`:
+///
+/// ```ignore
+/// harness::snapshot_tests! {
+///     example => [simulate_part1, simulate_part2],
+/// }
+/// ```
+#[macro_export]
+macro_rules! snapshot_tests {
+    ($($name:ident => [$($simulate:ident),+ $(,)?]),+ $(,)?) => {
+        #[cfg(test)]
+        mod snapshot_tests {
+            $(
+                mod $name {
+                    $(
+                        #[test]
+                        fn $simulate() {
+                            let input = $crate::testing::example_input(super::super::example_tests::$name::INPUT);
+                            let mut recorder = $crate::FrameRecorder::enabled();
+                            if let Err(e) = super::super::$simulate(&mut input.lines(), &mut recorder) {
+                                panic!("\n{}", e);
+                            }
+                            let frame = recorder.frames().last().expect("Nothing was drawn");
+                            let name = concat!(stringify!($name), "_", stringify!($simulate));
+                            $crate::snapshot::assert_snapshot(env!("CARGO_MANIFEST_DIR"), name, frame);
+                        }
+                    )+
+                }
+            )+
+        }
+    };
+}
+
+pub fn example_input(input: &str) -> &str {
+    input.strip_prefix('\n').unwrap_or(input)
+}
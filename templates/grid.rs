@@ -0,0 +1,32 @@
+// https://adventofcode.com/{{year}}/day/{{day}}
+use std::str::Lines;
+
+use harness::AocError;
+
+pub fn parse_grid(lines: &mut Lines) -> Vec<Vec<char>> {
+    lines.map(|line| line.chars().collect()).collect()
+}
+
+pub fn part1(lines: &mut Lines) -> Result<usize, AocError> {
+    let _grid = parse_grid(lines);
+    Ok(0)
+}
+
+pub fn part2(lines: &mut Lines) -> Result<usize, AocError> {
+    let _grid = parse_grid(lines);
+    Ok(0)
+}
+
+harness::solution! {
+    year: {{year}},
+    day: {{day}},
+    title: "",
+    tags: ["grid"],
+    algorithms: [],
+    difficulty: 0,
+}
+
+harness::example_tests! {
+    example: "
+" => { part1: 0, part2: 0 },
+}
@@ -0,0 +1,41 @@
+// https://adventofcode.com/{{year}}/day/{{day}}
+use std::str::Lines;
+
+use harness::AocError;
+
+pub fn parse_groups(lines: &mut Lines) -> Vec<Vec<String>> {
+    let mut groups = vec![Vec::new()];
+    for line in lines {
+        if line.is_empty() {
+            groups.push(Vec::new());
+        } else {
+            groups.last_mut().unwrap().push(line.to_string());
+        }
+    }
+    groups.retain(|g| !g.is_empty());
+    groups
+}
+
+pub fn part1(lines: &mut Lines) -> Result<usize, AocError> {
+    let _groups = parse_groups(lines);
+    Ok(0)
+}
+
+pub fn part2(lines: &mut Lines) -> Result<usize, AocError> {
+    let _groups = parse_groups(lines);
+    Ok(0)
+}
+
+harness::solution! {
+    year: {{year}},
+    day: {{day}},
+    title: "",
+    tags: ["parsing"],
+    algorithms: [],
+    difficulty: 0,
+}
+
+harness::example_tests! {
+    example: "
+" => { part1: 0, part2: 0 },
+}
@@ -0,0 +1,26 @@
+// https://adventofcode.com/{{year}}/day/{{day}}
+use std::str::Lines;
+
+use harness::AocError;
+
+pub fn part1(_lines: &mut Lines) -> Result<usize, AocError> {
+    Ok(0)
+}
+
+pub fn part2(_lines: &mut Lines) -> Result<usize, AocError> {
+    Ok(0)
+}
+
+harness::solution! {
+    year: {{year}},
+    day: {{day}},
+    title: "",
+    tags: [],
+    algorithms: [],
+    difficulty: 0,
+}
+
+harness::example_tests! {
+    example: "
+" => { part1: 0, part2: 0 },
+}